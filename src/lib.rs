@@ -1,39 +1,127 @@
-//! A relatively universal color format conversion tool that can convert between #RRGGBB, #RGB, #RRGGBBAA, hsl, hsla, hsv, cmyk.
+//! A relatively universal color format conversion tool that can convert between #RRGGBB, #RGB, #RRGGBBAA, hsl, hsla, hsv, hsva, cmyk.
 
+// The only non-snake-case name is the crate itself (`iColor`), and rustc reads
+// that lint's level from the crate root, so this cannot sit on a narrower item.
+#![allow(non_snake_case)]
+
+mod named;
+#[cfg(feature = "serde")]
+mod serde;
 mod utils;
 
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-#[derive(Debug, Default, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Color(u8, u8, u8, f32);
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ColorError {
-    Format,
+    /// The input is not a color in any supported format.
+    Format {
+        /// The string that failed to parse.
+        input: String,
+    },
+    /// A value of the color is out of range.
     Value,
 }
 
+impl ColorError {
+    pub(crate) fn format(input: &str) -> Self {
+        ColorError::Format { input: input.to_string() }
+    }
+}
+
+/// WCAG conformance level used by `Color::is_accessible`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WcagLevel {
+    /// Requires a contrast ratio of 4.5, or 3.0 for large text.
+    AA,
+    /// Requires a contrast ratio of 7.0, or 4.5 for large text.
+    AAA,
+}
+
+/// The notation a color string was written in, returned by `Color::parse_with_format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorFormat {
+    /// `#RRGGBB`
+    Hex,
+    /// `#RRGGBBAA`
+    HexAlpha,
+    /// `#RGB`
+    ShortHex,
+    /// `#RGBA`
+    ShortHexAlpha,
+    /// `rgb(R,G,B)`
+    Rgb,
+    /// `rgba(R,G,B,A)`, or `rgb(R G B / A)` with an alpha
+    Rgba,
+    /// `hsl(H,S%,L%)`
+    Hsl,
+    /// `hsla(H,S%,L%,A)`, or `hsl(H S% L% / A)` with an alpha
+    Hsla,
+    /// `hsv(H,S%,V%)`
+    Hsv,
+    /// `hsva(H,S%,V%,A)`
+    Hsva,
+    /// `hwb(H W% B%)`
+    Hwb,
+    /// `lab(L a b)`
+    Lab,
+    /// `lch(L C H)`
+    Lch,
+    /// `oklch(L C H)`
+    Oklch,
+    /// `cmyk(C,M,Y,K)`
+    Cmyk,
+    /// A CSS named color, ex: `red`
+    Named,
+}
+
+/// Builds a `Color` channel by channel, created by `Color::builder`.
+/// Unset channels default to 0 and the alpha to 1.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorBuilder {
+    red: u8,
+    green: u8,
+    blue: u8,
+    alpha: f32,
+}
+
 type ColorResult<T> = Result<T, ColorError>;
 
-const HEX_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^#(\w{2})(\w{2})(\w{2})$").unwrap());
-const HEX_WITH_TRANS_REG: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^#(\w{2})(\w{2})(\w{2})(\w{2})$").unwrap());
-const SHORT_HEX_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^#(\w)(\w)(\w)$").unwrap());
-const RGB_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^rgb\((\d+),(\d+),(\d+)\)$").unwrap());
-const RGBA_REG: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^rgba\((\d+),(\d+),(\d+),(\d+(\.\d+)?)\)$").unwrap());
-const HSL_REG:Lazy<Regex> = Lazy::new(|| Regex::new(r"^hsl\((\d+),(\d+)%,(\d+)%\)$").unwrap());
-const HSLA_REG:Lazy<Regex> = Lazy::new(|| Regex::new(r"^hsla\((\d+),(\d+)%,(\d+)%,(0\.\d+)\)$").unwrap());
-const CMYK_REG:Lazy<Regex> = Lazy::new(|| Regex::new(r"^cmyk\((\d+),(\d+),(\d+),(\d+)\)$").unwrap());
-const HSV_REG:Lazy<Regex> = Lazy::new(|| Regex::new(r"^hsv\((\d+),(\d+)%,(\d+)%\)$").unwrap());
+static HEX_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^#([0-9a-fA-F]{2})([0-9a-fA-F]{2})([0-9a-fA-F]{2})$").unwrap());
+static HEX_WITH_TRANS_REG: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^#([0-9a-fA-F]{2})([0-9a-fA-F]{2})([0-9a-fA-F]{2})([0-9a-fA-F]{2})$").unwrap());
+static SHORT_HEX_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^#([0-9a-fA-F])([0-9a-fA-F])([0-9a-fA-F])$").unwrap());
+static SHORT_HEX_WITH_TRANS_REG: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^#([0-9a-fA-F])([0-9a-fA-F])([0-9a-fA-F])([0-9a-fA-F])$").unwrap());
+static RGB_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^rgb\((\d+%?),(\d+%?),(\d+%?)\)$").unwrap());
+static RGBA_REG: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^rgba\((\d+%?),(\d+%?),(\d+%?),(\d+(\.\d+)?)\)$").unwrap());
+static HSL_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^hsl\((-?\d*\.?\d+(?:deg|grad|rad|turn)?),(\d+)%,(\d+)%\)$").unwrap());
+static HSLA_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^hsla\((-?\d*\.?\d+(?:deg|grad|rad|turn)?),(\d+)%,(\d+)%,(\d*\.?\d+)\)$").unwrap());
+static RGB_SPACE_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^rgba?\((\d+%?) (\d+%?) (\d+%?)(?:/(\d*\.?\d+%?))?\)$").unwrap());
+static HSL_SPACE_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^hsla?\((-?\d*\.?\d+(?:deg|grad|rad|turn)?) (\d*\.?\d+)% (\d*\.?\d+)%(?:/(\d*\.?\d+%?))?\)$").unwrap());
+static CMYK_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^cmyk\((\d+)%?,(\d+)%?,(\d+)%?,(\d+)%?\)$").unwrap());
+static HSV_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^hsv\((-?\d*\.?\d+(?:deg|grad|rad|turn)?),(\d+)%,(\d+)%\)$").unwrap());
+static HSVA_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^hsva\((-?\d*\.?\d+(?:deg|grad|rad|turn)?),(\d+)%,(\d+)%,(\d*\.?\d+)\)$").unwrap());
+static HWB_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^hwb\((-?\d*\.?\d+(?:deg|grad|rad|turn)?) (\d*\.?\d+)% (\d*\.?\d+)%\)$").unwrap());
+static LAB_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^lab\((\d*\.?\d+%?) (-?\d*\.?\d+%?) (-?\d*\.?\d+%?)\)$").unwrap());
+static LCH_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^lch\((\d*\.?\d+%?) (\d*\.?\d+%?) (-?\d*\.?\d+(?:deg|grad|rad|turn)?)\)$").unwrap());
+static OKLCH_REG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^oklch\((\d*\.?\d+%?) (\d*\.?\d+%?) (-?\d*\.?\d+(?:deg|grad|rad|turn)?)\)$").unwrap());
 
 impl Color {
     /// create Color from str.
     /// ## Arguments
-    /// * `color_str` - Specify the color, ex: `#FF00AA`, `#FF00AA80`, `rgb(129,45,78)`, `rgba(129,45,78, 0.8)`, `hsl(120, 45%, 90%)`, `hsla(120, 45%, 90%, 0.5)`, `hsv(120, 60%, 80%)`, `cmyk(100,40,70,90)` not case sensitive.
+    /// * `color_str` - Specify the color, ex: `#FF00AA`, `#F0A`, `#F0A8`, `#FF00AA80`, `rgb(129,45,78)`, `rgba(129,45,78, 0.8)`, `rgb(129 45 78 / 80%)`, `hsl(120, 45%, 90%)`, `hsla(120, 45%, 90%, 0.5)`, `hsv(120, 60%, 80%)`, `hsva(120, 60%, 80%, 0.5)`, `hwb(210 20% 30%)`, `lab(50% 40 59.5)`, `lch(52% 60 130)`, `oklch(70% 0.15 180)`, `cmyk(100,40,70,90)`, `red` not case sensitive, surrounding whitespace and whitespace between the values are ignored.
     /// ## Return
-    /// * `ColorResult<Color>`, if the `color_str` format is invalid, it will be return ColorError::Format error, else return Color
+    /// * `ColorResult<Color>`, if the `color_str` format is invalid, it will be return ColorError::Format error with the input, else return Color
     /// ## Example
     /// ``` rust
     /// use iColor::Color;
@@ -46,53 +134,114 @@ impl Color {
     /// let color6 = Color::from("hsla(120, 45%, 90%, 0.5)").unwrap();
     /// let color7 = Color::from("hsv(120, 60%, 80%)").unwrap();
     /// let color8 = Color::from("cmyk(100, 40, 70, 90)").unwrap();
+    /// let color9 = Color::from("CornflowerBlue").unwrap();
+    /// let color10 = Color::from("hsva(120, 60%, 80%, 0.5)").unwrap();
     /// ```
     /// 
     pub fn from(color: &str) -> ColorResult<Color> {
+        Color::parse_with_format(color).map(|(color, _)| color)
+    }
+
+    /// Parses a color string like `Color::from`, and also returns the notation it was written in.
+    /// ## Arguments
+    /// * `color_str` - Specify the color, in any format `Color::from` accepts.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::{Color, ColorFormat};
+    /// let (color, format) = Color::parse_with_format("hsl(120, 100%, 50%)").unwrap();
+    /// assert_eq!(color.to_hex(), "#00FF00");
+    /// assert_eq!(format, ColorFormat::Hsl);
+    /// ```
+    pub fn parse_with_format(color_str: &str) -> ColorResult<(Color, ColorFormat)> {
+        Color::parse(&utils::normalize(color_str)).map_err(|e| match e {
+            ColorError::Format { .. } => ColorError::format(color_str),
+            e => e,
+        })
+    }
+
+    fn parse(color: &str) -> ColorResult<(Color, ColorFormat)> {
         let len = color.len();
         if color.starts_with('#') {
-            // #RRGGBB || #RGB
-            if len == 4 || len == 7 {
-                return Color::from_hex(color);
+            // #RRGGBB
+            if len == 7 {
+                return Color::from_hex(color).map(|c| (c, ColorFormat::Hex));
+            }
+            // #RGB
+            if len == 4 {
+                return Color::from_hex(color).map(|c| (c, ColorFormat::ShortHex));
+            }
+            // #RGBA
+            if len == 5 {
+                return Color::from_short_hex_alpha(color).map(|c| (c, ColorFormat::ShortHexAlpha));
             }
             // #RRGGBBAA
             if len == 9 {
-                return Color::from_hex_alpha(color);
+                return Color::from_hex_alpha(color).map(|c| (c, ColorFormat::HexAlpha));
             }
         }
 
-        let color_str = color.replace(" ", "");
-        // rgb string
-        if color_str.starts_with("rgb(") {
-            return Color::from_rgb_str(color_str.as_str());
+        // rgb string, `rgb(R G B / A)` carries an alpha
+        if color.starts_with("rgb(") {
+            let format = if color.contains('/') { ColorFormat::Rgba } else { ColorFormat::Rgb };
+            return Color::from_rgb_str(color).map(|c| (c, format));
         }
 
         // rgba string
-        if color_str.starts_with("rgba(") {
-            return Color::from_rgba_str(color_str.as_str());
+        if color.starts_with("rgba(") {
+            return Color::from_rgba_str(color).map(|c| (c, ColorFormat::Rgba));
         }
 
-        // hsl string
-        if color_str.starts_with("hsl(") {
-            return Color::from_hsl_str(color_str.as_str());
+        // hsl string, `hsl(H S% L% / A)` carries an alpha
+        if color.starts_with("hsl(") {
+            let format = if color.contains('/') { ColorFormat::Hsla } else { ColorFormat::Hsl };
+            return Color::from_hsl_str(color).map(|c| (c, format));
         }
 
         // hsla string
-        if color_str.starts_with("hsla") {
-            return Color::from_hsla_str(color_str.as_str());
+        if color.starts_with("hsla") {
+            return Color::from_hsla_str(color).map(|c| (c, ColorFormat::Hsla));
         }
 
         // hsv string
-        if color_str.starts_with("hsv(") {
-            return Color::from_hsv_str(color_str.as_str());
+        if color.starts_with("hsv(") {
+            return Color::from_hsv_str(color).map(|c| (c, ColorFormat::Hsv));
+        }
+
+        // hsva string
+        if color.starts_with("hsva(") {
+            return Color::from_hsva_str(color).map(|c| (c, ColorFormat::Hsva));
+        }
+
+        // hwb string
+        if color.starts_with("hwb(") {
+            return Color::from_hwb_str(color).map(|c| (c, ColorFormat::Hwb));
+        }
+
+        // lab string
+        if color.starts_with("lab(") {
+            return Color::from_lab_str(color).map(|c| (c, ColorFormat::Lab));
+        }
+
+        // lch string
+        if color.starts_with("lch(") {
+            return Color::from_lch_str(color).map(|c| (c, ColorFormat::Lch));
+        }
+
+        // oklch string
+        if color.starts_with("oklch(") {
+            return Color::from_oklch_str(color).map(|c| (c, ColorFormat::Oklch));
         }
 
         // cmyk string
-        if color_str.starts_with("cmyk(") {
-            return Color::from_cmyk_str(color_str.as_str());
+        if color.starts_with("cmyk(") {
+            return Color::from_cmyk_str(color).map(|c| (c, ColorFormat::Cmyk));
         }
 
-        Err(ColorError::Format)
+        // css named color
+        if let Ok(c) = Color::from_name(color) {
+            return Ok((c, ColorFormat::Named));
+        }
+        Err(ColorError::format(color))
     }
     
     /// Generates a random `Color` instance with random values for red, green, blue, and alpha channels.
@@ -123,10 +272,27 @@ impl Color {
             let b = utils::match_to_num(cps.get(3).as_ref());
             return match (r, g, b) {
                 (Some(r), Some(g), Some(b)) => Ok(Color(r, g, b, 1.0)),
-                _ => Err(ColorError::Format),
+                _ => Err(ColorError::format(hex)),
             };
         }
-        Err(ColorError::Format)
+        Err(ColorError::format(hex))
+    }
+
+    /// Parses a hexadecimal color string of 3, 4, 6 or 8 digits, with or without the leading `#`, ex: `FF00AA` or `#F0A8`.
+    /// `Color::from` requires the `#`, otherwise a mistyped name like `bad` or `cafe` would silently parse as a color.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// assert_eq!(Color::from_bare_hex("ff00aa").unwrap().to_hex(), "#FF00AA");
+    /// assert!(Color::from("ff00aa").is_err());
+    /// ```
+    pub fn from_bare_hex(hex: &str) -> ColorResult<Color> {
+        let normalized = utils::normalize(hex);
+        let digits = normalized.strip_prefix('#').unwrap_or(&normalized);
+        if !matches!(digits.len(), 3 | 4 | 6 | 8) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColorError::format(hex));
+        }
+        Color::from(&format!("#{}", digits))
     }
 
     /// Parses a hexadecimal color string with alpha channel and returns a `Color` instance.
@@ -144,132 +310,323 @@ impl Color {
             let r = utils::match_to_num(cps.get(1).as_ref());
             let g = utils::match_to_num(cps.get(2).as_ref());
             let b = utils::match_to_num(cps.get(3).as_ref());
-            let a = utils::match_to_num2(cps.get(4).as_ref()).map(|v| (v / 255_u8) as f32);
+            let a = utils::match_to_num(cps.get(4).as_ref()).map(|v| v as f32 / 255.0);
+            return match (r, g, b, a) {
+                (Some(r), Some(g), Some(b), Some(a)) => Ok(Color(r, g, b, a)),
+                _ => Err(ColorError::format(hex_alpha)),
+            };
+        }
+        Err(ColorError::format(hex_alpha))
+    }
+
+    /// Parses a short hexadecimal color string with alpha channel and returns a `Color` instance,
+    /// each digit is doubled, so `#F00A` is the same as `#FF0000AA`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `hex_alpha` - A hexadecimal color string with alpha channel in the format of "#RGBA".
+    /// 
+    /// # Returns
+    /// 
+    /// A `Color` instance if the input string is a valid short hexadecimal color string with alpha channel, otherwise a `ColorError::Format` error.
+    pub fn from_short_hex_alpha(hex_alpha:&str) -> ColorResult<Color> {
+        if let Some(cps) = SHORT_HEX_WITH_TRANS_REG.captures(hex_alpha) {
+            let r = utils::match_to_num(cps.get(1).as_ref());
+            let g = utils::match_to_num(cps.get(2).as_ref());
+            let b = utils::match_to_num(cps.get(3).as_ref());
+            let a = utils::match_to_num(cps.get(4).as_ref()).map(|v| v as f32 / 255.0);
             return match (r, g, b, a) {
                 (Some(r), Some(g), Some(b), Some(a)) => Ok(Color(r, g, b, a)),
-                _ => Err(ColorError::Format),
+                _ => Err(ColorError::format(hex_alpha)),
             };
         }
-        Err(ColorError::Format)
+        Err(ColorError::format(hex_alpha))
     }
 
-    /// Parses a string in the format of "rgb(R,G,B)" and returns a `Color` instance.
+    /// Parses a string in the format of "rgb(R,G,B)" or "rgb(R%,G%,B%)" and returns a `Color` instance.
     /// 
     /// # Arguments
     /// 
-    /// * `rgb` - A string in the format of "rgb(R,G,B)".
+    /// * `rgb` - A string in the format of "rgb(R,G,B)" or "rgb(R%,G%,B%)", numbers and percentages can not be mixed,
+    ///   as in the CSS legacy syntax, a percentage is rounded to the nearest channel value, `50%` is `128`.
+    ///   The CSS Color 4 space separated form "rgb(R G B / A)" is accepted too, the alpha is optional and can be a percentage.
     /// 
     /// # Returns
     /// 
     /// A `Color` instance if the input string is a valid RGB string, otherwise a `ColorError::Format` error.
+    /// If a channel is greater than 255 or 100%, a `ColorError::Value` error.
     pub fn from_rgb_str(rgb:&str) -> ColorResult<Color> {
         if let Some(cps) = RGB_REG.captures(rgb) {
-            let r = utils::match_to_num2(cps.get(1).as_ref());
-            let g = utils::match_to_num2(cps.get(2).as_ref());
-            let b = utils::match_to_num2(cps.get(3).as_ref());
-            return match (r, g, b) {
-                (Some(r), Some(g), Some(b)) => Ok(Color(r, g, b, 1.0)),
-                _ => Err(ColorError::Format),
-            };
+            let (r, g, b) = utils::captures_to_rgb(&cps)?;
+            return Ok(Color(r, g, b, 1.0));
         }
-        Err(ColorError::Format)
+        Color::from_rgb_space_str(rgb)
     }
 
-    /// Parses a string in the format of "rgba(R,G,B,A)" and returns a `Color` instance.
+    /// Parses a string in the format of "rgba(R,G,B,A)" or "rgba(R%,G%,B%,A)" and returns a `Color` instance.
     /// 
     /// # Arguments
     /// 
-    /// * `rgba` - A string in the format of "rgba(R,G,B,A)".
+    /// * `rgba` - A string in the format of "rgba(R,G,B,A)" or "rgba(R%,G%,B%,A)", numbers and percentages can not be mixed,
+    ///   as in the CSS legacy syntax, a percentage is rounded to the nearest channel value, `50%` is `128`.
+    ///   The CSS Color 4 space separated form "rgba(R G B / A)" is accepted too, the alpha can be a percentage.
     /// 
     /// # Returns
     /// 
-    /// A `Color` instance if the input string is a valid RGB string, otherwise a `ColorError::Format` error.
+    /// A `Color` instance if the input string is a valid RGBA string, otherwise a `ColorError::Format` error.
+    /// If a channel is greater than 255 or 100%, or the alpha is out of 0.0 - 1.0, a `ColorError::Value` error.
     pub fn from_rgba_str(rgba:&str) -> ColorResult<Color> {
         if let Some(cps) = RGBA_REG.captures(rgba) {
-            let r = utils::match_to_num2(cps.get(1).as_ref());
-            let g = utils::match_to_num2(cps.get(2).as_ref());
-            let b = utils::match_to_num2(cps.get(3).as_ref());
-            let a = cps.get(3).and_then(|v| v.as_str().parse::<f32>().ok());
-            return match (r, g, b, a) {
-                (Some(r), Some(g), Some(b), Some(a)) => Ok(Color(r, g, b, a)),
-                _ => Err(ColorError::Format),
+            let (r, g, b) = utils::captures_to_rgb(&cps)?;
+            return match cps.get(4).and_then(|v| v.as_str().parse::<f32>().ok()) {
+                Some(a) => Color::from_rgba(r, g, b, a),
+                None => Err(ColorError::format(rgba)),
+            };
+        }
+        Color::from_rgb_space_str(rgba)
+    }
+
+    /// Parses the CSS Color 4 space separated form "rgb(R G B / A)", shared by `from_rgb_str` and `from_rgba_str`.
+    fn from_rgb_space_str(rgb:&str) -> ColorResult<Color> {
+        if let Some(cps) = RGB_SPACE_REG.captures(rgb) {
+            let (r, g, b) = utils::captures_to_rgb(&cps)?;
+            return match cps.get(4).map(|a| utils::parse_number_or_percent(a.as_str(), 1.0)) {
+                None => Ok(Color(r, g, b, 1.0)),
+                Some(Some(a)) => Color::from_rgba(r, g, b, a),
+                Some(None) => Err(ColorError::format(rgb)),
             };
         }
-        Err(ColorError::Format)
+        Err(ColorError::format(rgb))
     }
 
     /// Parses a string in the format of "hsl(H,S,L)" and returns a `Color` instance.
     /// 
     /// # Arguments
     /// 
-    /// * `hsl` - A string in the format of "hsl(H,S,L)".
+    /// * `hsl` - A string in the format of "hsl(H,S,L)", the hue can have a `deg`, `rad`, `grad` or `turn` unit, degrees by default.
+    ///   The CSS Color 4 space separated form "hsl(H S% L% / A)" is accepted too, the alpha is optional and can be a percentage.
     /// 
     /// # Returns
     /// 
     /// A `Color` instance if the input string is a valid RGB string, otherwise a `ColorError::Format` error.
     pub fn from_hsl_str(hsl:&str) -> ColorResult<Color> {
         if let Some(cps) = HSL_REG.captures(hsl) {
-            let h = cps.get(1).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
+            let h = cps.get(1).map(|c| c.as_str()).and_then(utils::parse_hue);
             let s = cps.get(2).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
             let l = cps.get(3).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
             return match (h, s ,l) {
                 (Some(h), Some(s), Some(l)) => Color::from_hsl(h,s as f32 / 100.0,l as f32 / 100.0),
-                _ => Err(ColorError::Format)
+                _ => Err(ColorError::format(hsl))
             };
         }
-        Err(ColorError::Format)
+        Color::from_hsl_space_str(hsl)
     }
 
     /// Parses a string in the format of "hsla(H,S,L,A)" and returns a `Color` instance.
     /// 
     /// # Arguments
     /// 
-    /// * `hsla` - A string in the format of "hsla(H,S,L,A)".
+    /// * `hsla` - A string in the format of "hsla(H,S,L,A)", the hue can have a `deg`, `rad`, `grad` or `turn` unit, degrees by default.
+    ///   The CSS Color 4 space separated form "hsla(H S% L% / A)" is accepted too, the alpha can be a percentage.
     /// 
     /// # Returns
     /// 
-    /// A `Color` instance if the input string is a valid RGB string, otherwise a `ColorError::Format` error.
+    /// A `Color` instance if the input string is a valid HSLA string, otherwise a `ColorError::Format` error.
+    /// If the alpha is out of 0.0 - 1.0, a `ColorError::Value` error.
     pub fn from_hsla_str(hsla:&str) -> ColorResult<Color> {
         if let Some(cps) = HSLA_REG.captures(hsla) {
-            let h = cps.get(1).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
+            let h = cps.get(1).map(|c| c.as_str()).and_then(utils::parse_hue);
             let s = cps.get(2).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
             let l = cps.get(3).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
             let a = cps.get(4).map(|c| c.as_str()).and_then(|s| s.parse::<f32>().ok());
             return match (h, s ,l, a) {
                 (Some(h), Some(s), Some(l), Some(a)) => Color::from_hsla(h,s as f32 / 100.0,l as f32 / 100.0, a),
-                _ => Err(ColorError::Format)
+                _ => Err(ColorError::format(hsla))
+            };
+        }
+        Color::from_hsl_space_str(hsla)
+    }
+
+    /// Parses the CSS Color 4 space separated form "hsl(H S% L% / A)", shared by `from_hsl_str` and `from_hsla_str`,
+    /// the saturation and lightness may be fractional.
+    fn from_hsl_space_str(hsl:&str) -> ColorResult<Color> {
+        if let Some(cps) = HSL_SPACE_REG.captures(hsl) {
+            let h = cps.get(1).map(|c| c.as_str()).and_then(utils::parse_hue);
+            let s = cps.get(2).map(|c| c.as_str()).and_then(|s| s.parse::<f32>().ok());
+            let l = cps.get(3).map(|c| c.as_str()).and_then(|s| s.parse::<f32>().ok());
+            let a = cps.get(4).map_or(Some(1.0), |a| utils::parse_number_or_percent(a.as_str(), 1.0));
+            return match (h, s, l, a) {
+                (Some(h), Some(s), Some(l), Some(a)) => Color::from_hsla(h, s / 100.0, l / 100.0, a),
+                _ => Err(ColorError::format(hsl))
             };
         }
-        Err(ColorError::Format)
+        Err(ColorError::format(hsl))
     }
 
     /// Parses a string in the format of "hsv(H,S,V)" and returns a `Color` instance.
     /// 
     /// # Arguments
     /// 
-    /// * `hsv` - A string in the format of "hsv(H,S,V)".
+    /// * `hsv` - A string in the format of "hsv(H,S,V)", the hue can have a `deg`, `rad`, `grad` or `turn` unit, degrees by default.
     /// 
     /// # Returns
     /// 
     /// A `Color` instance if the input string is a valid RGB string, otherwise a `ColorError::Format` error.
     pub fn from_hsv_str(hsv:&str) -> ColorResult<Color> {
         if let Some(cps) = HSV_REG.captures(hsv) {
-            let h = cps.get(1).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
+            let h = cps.get(1).map(|c| c.as_str()).and_then(utils::parse_hue);
             let s = cps.get(2).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
             let v = cps.get(3).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
             return match (h, s ,v) {
                 (Some(h), Some(s), Some(v)) => Color::from_hsv(h,s as f32 / 100.0,v as f32 / 100.0),
-                _ => Err(ColorError::Format)
+                _ => Err(ColorError::format(hsv))
+            };
+        }
+        Err(ColorError::format(hsv))
+    }
+
+    /// Parses a string in the format of "hsva(H,S,V,A)" and returns a `Color` instance.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `hsva` - A string in the format of "hsva(H,S,V,A)", the hue can have a `deg`, `rad`, `grad` or `turn` unit, degrees by default.
+    /// 
+    /// # Returns
+    /// 
+    /// A `Color` instance if the input string is a valid HSVA string, otherwise a `ColorError::Format` error.
+    /// If the alpha is out of 0.0 - 1.0, a `ColorError::Value` error.
+    pub fn from_hsva_str(hsva:&str) -> ColorResult<Color> {
+        if let Some(cps) = HSVA_REG.captures(hsva) {
+            let h = cps.get(1).map(|c| c.as_str()).and_then(utils::parse_hue);
+            let s = cps.get(2).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
+            let v = cps.get(3).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
+            let a = cps.get(4).map(|c| c.as_str()).and_then(|s| s.parse::<f32>().ok());
+            return match (h, s ,v, a) {
+                (Some(h), Some(s), Some(v), Some(a)) => Color::from_hsva(h,s as f32 / 100.0,v as f32 / 100.0, a),
+                _ => Err(ColorError::format(hsva))
+            };
+        }
+        Err(ColorError::format(hsva))
+    }
+
+    /// Parses a string in the format of "hwb(H W% B%)" and returns a `Color` instance.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `hwb` - A string in the format of "hwb(H W% B%)", the hue can have a `deg`, `rad`, `grad` or `turn` unit, degrees by default.
+    /// 
+    /// # Returns
+    /// 
+    /// A `Color` instance if the input string is a valid HWB string, otherwise a `ColorError::Format` error.
+    /// If the whiteness or blackness is above 100%, a `ColorError::Value` error.
+    pub fn from_hwb_str(hwb:&str) -> ColorResult<Color> {
+        if let Some(cps) = HWB_REG.captures(hwb) {
+            let h = cps.get(1).map(|c| c.as_str()).and_then(utils::parse_hue);
+            let w = cps.get(2).map(|c| c.as_str()).and_then(|s| s.parse::<f32>().ok());
+            let b = cps.get(3).map(|c| c.as_str()).and_then(|s| s.parse::<f32>().ok());
+            return match (h, w, b) {
+                (Some(h), Some(w), Some(b)) => Color::from_hwb(h, w / 100.0, b / 100.0),
+                _ => Err(ColorError::format(hwb))
+            };
+        }
+        Err(ColorError::format(hwb))
+    }
+
+    /// Parses a string in the format of "lab(L a b)" and returns a `Color` instance.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `lab` - A string in the format of "lab(L a b)", the lightness is a number in 0 - 100 or a percentage,
+    ///   a and b are numbers or percentages where 100% is 125.
+    /// 
+    /// # Returns
+    /// 
+    /// A `Color` instance if the input string is a valid LAB string, otherwise a `ColorError::Format` error.
+    /// If a value is out of range, a `ColorError::Value` error.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_lab_str("lab(53.24% 80.09 67.2)").unwrap();
+    /// assert_eq!(color.to_hex(), "#FF0000");
+    /// ```
+    pub fn from_lab_str(lab:&str) -> ColorResult<Color> {
+        if let Some(cps) = LAB_REG.captures(lab) {
+            let l = cps.get(1).and_then(|c| utils::parse_number_or_percent(c.as_str(), 100.0));
+            let a = cps.get(2).and_then(|c| utils::parse_number_or_percent(c.as_str(), 125.0));
+            let b = cps.get(3).and_then(|c| utils::parse_number_or_percent(c.as_str(), 125.0));
+            return match (l, a, b) {
+                (Some(l), Some(a), Some(b)) => Color::from_lab(l, a, b),
+                _ => Err(ColorError::format(lab))
+            };
+        }
+        Err(ColorError::format(lab))
+    }
+
+    /// Parses a string in the format of "lch(L C H)" and returns a `Color` instance.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `lch` - A string in the format of "lch(L C H)", the lightness is a number in 0 - 100 or a percentage,
+    ///   the chroma is a number or a percentage where 100% is 150, the hue can have a `deg`, `rad`, `grad` or `turn` unit, degrees by default.
+    /// 
+    /// # Returns
+    /// 
+    /// A `Color` instance if the input string is a valid LCH string, otherwise a `ColorError::Format` error.
+    /// If a value is out of range, a `ColorError::Value` error.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_lch_str("lch(53.24% 104.55 40)").unwrap();
+    /// assert_eq!(color.to_hex(), "#FF0000");
+    /// ```
+    pub fn from_lch_str(lch:&str) -> ColorResult<Color> {
+        if let Some(cps) = LCH_REG.captures(lch) {
+            let l = cps.get(1).and_then(|c| utils::parse_number_or_percent(c.as_str(), 100.0));
+            let c = cps.get(2).and_then(|c| utils::parse_number_or_percent(c.as_str(), 150.0));
+            let h = cps.get(3).and_then(|c| utils::parse_angle(c.as_str()));
+            return match (l, c, h) {
+                (Some(l), Some(c), Some(h)) => Color::from_lch(l, c, h),
+                _ => Err(ColorError::format(lch))
+            };
+        }
+        Err(ColorError::format(lch))
+    }
+
+    /// Parses a string in the format of "oklch(L C H)" and returns a `Color` instance.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `oklch` - A string in the format of "oklch(L C H)", the lightness is a number in 0 - 1 or a percentage,
+    ///   the chroma is a number or a percentage where 100% is 0.4, the hue can have a `deg`, `rad`, `grad` or `turn` unit, degrees by default.
+    /// 
+    /// # Returns
+    /// 
+    /// A `Color` instance if the input string is a valid OKLCH string, otherwise a `ColorError::Format` error.
+    /// If a value is out of range, a `ColorError::Value` error.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_oklch_str("oklch(62.8% 0.2577 29.23)").unwrap();
+    /// assert_eq!(color.to_hex(), "#FF0000");
+    /// ```
+    pub fn from_oklch_str(oklch:&str) -> ColorResult<Color> {
+        if let Some(cps) = OKLCH_REG.captures(oklch) {
+            let l = cps.get(1).and_then(|c| utils::parse_number_or_percent(c.as_str(), 1.0));
+            let c = cps.get(2).and_then(|c| utils::parse_number_or_percent(c.as_str(), 0.4));
+            let h = cps.get(3).and_then(|c| utils::parse_angle(c.as_str()));
+            return match (l, c, h) {
+                (Some(l), Some(c), Some(h)) => Color::from_oklch(l, c, h),
+                _ => Err(ColorError::format(oklch))
             };
         }
-        Err(ColorError::Format)
+        Err(ColorError::format(oklch))
     }
 
-    /// Parses a string in the format of "cmyk(C,M,Y,K)" and returns a `Color` instance.
+    /// Parses a string in the format of "cmyk(C,M,Y,K)" or "cmyk(C%,M%,Y%,K%)" and returns a `Color` instance.
     /// 
     /// # Arguments
     /// 
-    /// * `cmyk` - A string in the format of "cmyk(C,M,Y,K)".
+    /// * `cmyk` - A string in the format of "cmyk(C,M,Y,K)" or "cmyk(C%,M%,Y%,K%)".
     /// 
     /// # Returns
     /// 
@@ -282,15 +639,41 @@ impl Color {
             let k = cps.get(4).map(|c| c.as_str()).and_then(|s| s.parse::<u32>().ok());
             return match (c,m,y,k) {
                 (Some(c), Some(m), Some(y), Some(k)) => Color::from_cmyk(c as f32 / 100.0,m as f32 / 100.0,y as f32 / 100.0, k as f32 / 100.0),
-                _ => Err(ColorError::Format)
+                _ => Err(ColorError::format(cmyk))
             };
         }
-        Err(ColorError::Format)
+        Err(ColorError::format(cmyk))
+    }
+
+    /// Parses a CSS named color and returns a `Color` instance, `transparent` is black with alpha 0.0.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `name` - A CSS color keyword, ex: `red`, `rebeccapurple`, not case sensitive.
+    /// 
+    /// # Returns
+    /// 
+    /// A `Color` instance if the name is a CSS named color, otherwise a `ColorError::Format` error.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_name("CornflowerBlue").unwrap();
+    /// assert_eq!(color.to_hex(), "#6495ED");
+    /// ```
+    pub fn from_name(name:&str) -> ColorResult<Color> {
+        let lowercase = name.to_lowercase();
+        if lowercase == "transparent" {
+            return Ok(Color(0, 0, 0, 0.0));
+        }
+        match named::lookup(&lowercase) {
+            Some((r, g, b)) => Ok(Color(r, g, b, 1.0)),
+            None => Err(ColorError::format(name)),
+        }
     }
 
     /// create Color from hsl
     /// ## Arguments
-    /// * h  - Specify the Hue, the value need be between in 0 - 360
+    /// * h  - Specify the Hue in degrees, values of 360 and above wrap around
     /// * s  - Specify the Saturation, the value need be between in 0.0 - 1.0
     /// * l  - Specify teh Lightness, the value need be between in 0.0 - 1.0
     /// ## Example
@@ -300,30 +683,17 @@ impl Color {
     /// assert_eq!(color.to_hex(), "#104C88");
     /// ```
     pub fn from_hsl(h: u32, s: f32, l: f32) -> ColorResult<Color> {
-        if !utils::is_valid_num(&s) || !utils::is_valid_num(&l) || !(0..=360).contains(&h) {
+        if !utils::is_valid_num(&s) || !utils::is_valid_num(&l) {
             return Err(ColorError::Value);
         }
-        let c = (1.0 - (l * 2.0 - 1.0).abs()) * s;
-        let x = c * (1.0 - ((h as f32 / 60.0) % 2.0 - 1.0).abs());
-        let m = l - c / 2.0;
-        let (mut r, mut g, mut b) = match h {
-            n if n < 60 => (c, x, 0.0),
-            n if 60 <= n && n < 120 => (x, c, 0.0),
-            n if 120 <= n && n < 180 => (0.0, c, x),
-            n if 180 <= n && n < 240 => (0.0, x, c),
-            n if 240 <= n && n < 300 => (x, 0.0, c),
-            n if 300 <= n && n < 360 => (c, 0.0, x),
-            _ => (0.0, 0.0, 0.0),
-        };
-        r = (r + m) * 255.0;
-        g = (g + m) * 255.0;
-        b = (b + m) * 255.0;
+        // hue wraps around, 360° is the same hue as 0°
+        let (r, g, b) = utils::hsl_to_rgb((h % 360) as f32, s, l);
         Ok(Color(r as u8, g as u8, b as u8, 1.0))
     }
 
     /// create Color from hsla
     /// ## Arguments
-    /// * h  - Specify the Hue, the value need be between in 0 - 360
+    /// * h  - Specify the Hue in degrees, values of 360 and above wrap around
     /// * s  - Specify the Saturation, the value need be between in 0.0 - 1.0
     /// * l  - Specify teh Lightness, the value need be between in 0.0 - 1.0
     /// * a  - Specify the Alpha, the value need be between in 0.0 - 1.0
@@ -334,11 +704,59 @@ impl Color {
     /// assert_eq!(color.to_hex(), "#87A5C3");
     /// ```
     pub fn from_hsla(h: u32, s: f32, l: f32, a: f32) -> ColorResult<Color> {
+        if !utils::is_valid_num(&a) {
+            return Err(ColorError::Value);
+        }
         let mut color = Color::from_hsl(h, s, l)?;
         color.set_alpha(a);
         Ok(color)
     }
 
+    /// create Color from rgb at compile time, the alpha is 1.0.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// const BRAND: Color = Color::rgb(16, 76, 136);
+    /// assert_eq!(BRAND.to_hex(), "#104C88");
+    /// ```
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color(r, g, b, 1.0)
+    }
+
+    /// create Color from rgba at compile time.
+    /// Unlike `from_rgba` it never fails, the alpha is clamped into 0.0 - 1.0 and a NaN alpha becomes 1.0.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// const OVERLAY: Color = Color::rgba(0, 0, 0, 0.5);
+    /// assert_eq!(OVERLAY.to_rgba(), "rgba(0,0,0,0.5)");
+    /// ```
+    pub const fn rgba(r: u8, g: u8, b: u8, a: f32) -> Color {
+        Color(r, g, b, utils::clamp_alpha(a))
+    }
+
+    /// create Color from rgb at compile time, the same as `Color::rgb`.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// const BRAND: Color = Color::rgb_const(16, 76, 136);
+    /// assert_eq!(BRAND, Color::rgb(16, 76, 136));
+    /// ```
+    pub const fn rgb_const(r: u8, g: u8, b: u8) -> Color {
+        Color::rgb(r, g, b)
+    }
+
+    /// create Color from rgba at compile time, the same as `Color::rgba`.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// const OVERLAY: Color = Color::rgba_const(0, 0, 0, 0.5);
+    /// assert_eq!(OVERLAY, Color::rgba(0, 0, 0, 0.5));
+    /// ```
+    pub const fn rgba_const(r: u8, g: u8, b: u8, a: f32) -> Color {
+        Color::rgba(r, g, b, a)
+    }
+
     /// create Color from rgb
     /// ## Arguments
     /// * r  - Specify the Red, the value need be between in 0 - 255
@@ -367,11 +785,64 @@ impl Color {
     /// assert_eq!(color.to_hex(), "#87A5C3");
     /// 
     pub fn from_rgba(r: u8, g: u8, b: u8, a: f32) -> ColorResult<Color> {
-        return if !utils::is_valid_num(&a) {
+        if !utils::is_valid_num(&a) {
             Err(ColorError::Value)
         } else {
             Ok(Color(r, g, b, a))
-        }      
+        }
+    }
+
+    /// create Color from rgba, unlike `from_rgba` it never fails, an alpha out of 0.0 - 1.0 is clamped into range
+    /// and a NaN alpha becomes 1.0.
+    /// ## Arguments
+    /// * r  - Specify the Red, the value need be between in 0 - 255
+    /// * g  - Specify the Green, the value need be between in 0 - 255
+    /// * b  - Specify the Blue, the value need be between in 0 - 255
+    /// * a  - Specify the Alpha, clamped into 0.0 - 1.0, NaN is treated as 1.0
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba_clamped(16, 76, 136, 1.5);
+    /// assert_eq!(color.alpha(), 1.0);
+    /// ```
+    pub fn from_rgba_clamped(r: u8, g: u8, b: u8, a: f32) -> Color {
+        Color(r, g, b, utils::clamp_alpha(a))
+    }
+
+    /// create Color from a packed `0xRRGGBBAA` integer, red is the most significant byte and alpha the least.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_u32(0xFF00AAFF);
+    /// assert_eq!(color.to_hex(), "#FF00AA");
+    /// ```
+    pub fn from_u32(value: u32) -> Color {
+        let [r, g, b, a] = value.to_be_bytes();
+        Color(r, g, b, a as f32 / 255.0)
+    }
+
+    /// create an opaque Color from a packed `0xRRGGBB` integer, the highest byte is ignored.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_u32_rgb(0xFF00AA);
+    /// assert_eq!(color.to_hex(), "#FF00AA");
+    /// ```
+    pub fn from_u32_rgb(value: u32) -> Color {
+        let [_, r, g, b] = value.to_be_bytes();
+        Color(r, g, b, 1.0)
+    }
+
+    /// create Color from a packed `0xAARRGGBB` integer, alpha is the most significant byte.
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_u32_argb(0x80FF00AA);
+    /// assert_eq!(color.to_hex_alpha(), "#FF00AA80");
+    /// ```
+    pub fn from_u32_argb(value: u32) -> Color {
+        let [a, r, g, b] = value.to_be_bytes();
+        Color(r, g, b, a as f32 / 255.0)
     }
 
     /// create Color from cmyk
@@ -403,7 +874,7 @@ impl Color {
 
     /// create Color from hsv
     /// ## Arguments
-    /// * h  - Specify the Hue, the value need be between in 0 - 360
+    /// * h  - Specify the Hue in degrees, values of 360 and above wrap around
     /// * s  - Specify the Saturation, the value need be between in 0.0 - 1.0
     /// * v  - Specify the Value, the value need be between in 0.0 - 1.0
     /// ## Example
@@ -413,19 +884,21 @@ impl Color {
     /// assert_eq!(color.to_hex(), "#729FCC");
     /// 
     pub fn from_hsv(h: u32, s: f32, v: f32) -> ColorResult<Color> {
-        if !utils::is_valid_num(&s) || !utils::is_valid_num(&v) || !(0..=360).contains(&h) {
+        if !utils::is_valid_num(&s) || !utils::is_valid_num(&v) {
             return Err(ColorError::Value);
         }
+        // hue wraps around, 360° is the same hue as 0°
+        let h = h % 360;
         let c = v * s;
         let x = c * (1.0 - ((h as f32 / 60.0) % 2.0 - 1.0).abs());
         let m = v - c;
         let (mut r, mut g, mut b) = match h {
-            n if n < 60 => (c, x, 0.0),
-            n if 60 <= n && n < 120 => (x, c, 0.0),
-            n if 120 <= n && n < 180 => (0.0, c, x),
-            n if 180 <= n && n < 240 => (0.0, x, c),
-            n if 240 <= n && n < 300 => (x, 0.0, c),
-            n if 300 <= n && n < 360 => (c, 0.0, x),
+            0..=59 => (c, x, 0.0),
+            60..=119 => (x, c, 0.0),
+            120..=179 => (0.0, c, x),
+            180..=239 => (0.0, x, c),
+            240..=299 => (x, 0.0, c),
+            300..=359 => (c, 0.0, x),
             _ => (0.0, 0.0, 0.0),
         };
         r = (r + m) * 255.0;
@@ -434,62 +907,273 @@ impl Color {
         Ok(Color(r as u8, g as u8, b as u8, 1.0))
     }
 
-    /// Convert the color to a hexadecimal string representation.
-    /// If the alpha channel is not 1.0, it will be computed with reg, green, and blue.
-    /// 
-    /// # Example
+    /// create Color from hwb, when the whiteness and blackness add up to more than 1.0
+    /// they are scaled down proportionally, which gives a gray.
+    /// ## Arguments
+    /// * h  - Specify the Hue in degrees, values of 360 and above wrap around
+    /// * w  - Specify the Whiteness, the value need be between in 0.0 - 1.0
+    /// * b  - Specify the Blackness, the value need be between in 0.0 - 1.0
+    /// ## Example
     /// ``` rust
     /// use iColor::Color;
-    /// let color = Color::from_rgb(255, 0, 0).unwrap();
-    /// assert_eq!(color.to_hex(), "#FF0000");
-    /// 
-    /// let color2 = Color::from_rgba(0,0,0,0.5).unwrap();
-    /// assert_eq!(color2.to_hex(), "#7F7F7F");
+    /// let color = Color::from_hwb(210, 0.2, 0.3).unwrap();
+    /// assert_eq!(color.to_hex(), "#3272B2");
     /// ```
-    
-    pub fn to_hex(&self) -> String {
-        let r = utils::calc_rgb_with_alpha(self.0, self.3) as u8;
-        let g = utils::calc_rgb_with_alpha(self.1, self.3) as u8;
-        let b = utils::calc_rgb_with_alpha(self.2, self.3) as u8;
-        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    pub fn from_hwb(h: u32, w: f32, b: f32) -> ColorResult<Color> {
+        if !utils::is_valid_num(&w) || !utils::is_valid_num(&b) {
+            return Err(ColorError::Value);
+        }
+        if w + b >= 1.0 {
+            return Color::from_hsv(0, 0.0, w / (w + b));
+        }
+        Color::from_hsv(h, 1.0 - w / (1.0 - b), 1.0 - b)
     }
 
-    /// Convert the color to a hexadecimal string with alpha representation.
-    /// ```
+    /// create Color from CIE XYZ with the D65 white point, where Y of white is 1.0.
+    /// Colors outside of the sRGB gamut are clamped.
+    /// ## Arguments
+    /// * x, y, z  - Specify the tristimulus values, the values need be finite and not negative
+    /// ## Example
+    /// ``` rust
     /// use iColor::Color;
-    /// let color = Color::from_rgba(255, 0, 0, 0.5).unwrap();
-    /// assert_eq!(color.to_hex_alpha(), "#FF00007F");
+    /// let color = Color::from_xyz(0.9505, 1.0, 1.089).unwrap();
+    /// assert_eq!(color.to_hex(), "#FFFFFF");
     /// ```
-    pub fn to_hex_alpha(&self) -> String {
-        format!(
-            "#{:02X}{:02X}{:02X}{:02X}",
-            self.0,
-            self.1,
-            self.2,
-            (self.3 * 255.0) as u8
-        )
+    pub fn from_xyz(x: f32, y: f32, z: f32) -> ColorResult<Color> {
+        if [x, y, z].iter().any(|v| !v.is_finite() || *v < 0.0) {
+            return Err(ColorError::Value);
+        }
+        let (r, g, b) = utils::xyz_to_rgb(x, y, z);
+        Ok(Color(r, g, b, 1.0))
     }
 
-    /// Convert the color to the format required by Excel, where the color format is usually #AARRGGBB, where AA is alpha
-    /// ```
+    /// create Color from CIELAB, converted through CIE XYZ with the D65 white point.
+    /// Colors outside of the sRGB gamut are clamped.
+    /// ## Arguments
+    /// * l  - Specify the Lightness, the value need be between in 0.0 - 100.0
+    /// * a  - Specify the green-red axis, the value need be between in -128.0 - 128.0
+    /// * b  - Specify the blue-yellow axis, the value need be between in -128.0 - 128.0
+    /// ## Example
+    /// ``` rust
     /// use iColor::Color;
-    /// let color = Color::from("#FF0000").unwrap();
-    /// assert_eq!(color.to_alpha_hex(), "#FFFF0000");
+    /// let color = Color::from_lab(53.24, 80.09, 67.2).unwrap();
+    /// assert_eq!(color.to_hex(), "#FF0000");
+    /// ```
+    pub fn from_lab(l: f32, a: f32, b: f32) -> ColorResult<Color> {
+        if !(0.0..=100.0).contains(&l) || !(-128.0..=128.0).contains(&a) || !(-128.0..=128.0).contains(&b) {
+            return Err(ColorError::Value);
+        }
+        let (x, y, z) = utils::lab_to_xyz(l, a, b);
+        let (r, g, b) = utils::xyz_to_rgb(x, y, z);
+        Ok(Color(r, g, b, 1.0))
+    }
+
+    /// create Color from CIE LCh, the cylindrical form of CIELAB.
+    /// Colors outside of the sRGB gamut are clamped.
+    /// ## Arguments
+    /// * l  - Specify the Lightness, the value need be between in 0.0 - 100.0
+    /// * c  - Specify the Chroma, the value need be not negative
+    /// * h  - Specify the Hue in degrees, values out of 0.0 - 360.0 wrap around
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_lch(53.24, 104.55, 40.0).unwrap();
+    /// assert_eq!(color.to_hex(), "#FF0000");
+    /// ```
+    pub fn from_lch(l: f32, c: f32, h: f32) -> ColorResult<Color> {
+        if !(0.0..=100.0).contains(&l) || !c.is_finite() || c < 0.0 || !h.is_finite() {
+            return Err(ColorError::Value);
+        }
+        let (l, a, b) = utils::lch_to_lab(l, c, h.rem_euclid(360.0));
+        let (x, y, z) = utils::lab_to_xyz(l, a, b);
+        let (r, g, b) = utils::xyz_to_rgb(x, y, z);
+        Ok(Color(r, g, b, 1.0))
+    }
+
+    /// create Color from OKLab, a perceptually uniform color space.
+    /// Colors outside of the sRGB gamut are clamped.
+    /// ## Arguments
+    /// * l  - Specify the Lightness, the value need be between in 0.0 - 1.0
+    /// * a  - Specify the green-red axis, the value need be between in -0.5 - 0.5
+    /// * b  - Specify the blue-yellow axis, the value need be between in -0.5 - 0.5
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_oklab(0.628, 0.2249, 0.1258).unwrap();
+    /// assert_eq!(color.to_hex(), "#FF0000");
+    /// ```
+    pub fn from_oklab(l: f32, a: f32, b: f32) -> ColorResult<Color> {
+        if !utils::is_valid_num(&l) || !(-0.5..=0.5).contains(&a) || !(-0.5..=0.5).contains(&b) {
+            return Err(ColorError::Value);
+        }
+        let (r, g, b) = utils::oklab_to_rgb(l, a, b);
+        Ok(Color(r, g, b, 1.0))
+    }
+
+    /// create Color from OKLCH, the cylindrical form of OKLab.
+    /// Colors outside of the sRGB gamut are clamped.
+    /// ## Arguments
+    /// * l  - Specify the Lightness, the value need be between in 0.0 - 1.0
+    /// * c  - Specify the Chroma, the value need be between in 0.0 - 0.5
+    /// * h  - Specify the Hue in degrees, values out of 0.0 - 360.0 wrap around
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_oklch(0.628, 0.2577, 29.23).unwrap();
+    /// assert_eq!(color.to_hex(), "#FF0000");
+    /// ```
+    pub fn from_oklch(l: f32, c: f32, h: f32) -> ColorResult<Color> {
+        if !utils::is_valid_num(&l) || !(0.0..=0.5).contains(&c) || !h.is_finite() {
+            return Err(ColorError::Value);
+        }
+        let (l, a, b) = utils::lch_to_lab(l, c, h.rem_euclid(360.0));
+        let (r, g, b) = utils::oklab_to_rgb(l, a, b);
+        Ok(Color(r, g, b, 1.0))
+    }
+
+    /// create Color from hsva
+    /// ## Arguments
+    /// * h  - Specify the Hue in degrees, values of 360 and above wrap around
+    /// * s  - Specify the Saturation, the value need be between in 0.0 - 1.0
+    /// * v  - Specify the Value, the value need be between in 0.0 - 1.0
+    /// * a  - Specify the Alpha, the value need be between in 0.0 - 1.0
+    /// ## Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_hsva(210, 0.44, 0.8, 0.5).unwrap();
+    /// assert_eq!(color.to_hsva(), "hsva(210,44%,80%,0.5)");
+    /// ```
+    pub fn from_hsva(h: u32, s: f32, v: f32, a: f32) -> ColorResult<Color> {
+        if !utils::is_valid_num(&a) {
+            return Err(ColorError::Value);
+        }
+        let mut color = Color::from_hsv(h, s, v)?;
+        color.set_alpha(a);
+        Ok(color)
+    }
+
+    /// Convert the color to a hexadecimal string representation.
+    /// If the alpha channel is not 1.0, it will be computed with reg, green, and blue.
+    /// 
+    /// # Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgb(255, 0, 0).unwrap();
+    /// assert_eq!(color.to_hex(), "#FF0000");
+    /// 
+    /// let color2 = Color::from_rgba(0,0,0,0.5).unwrap();
+    /// assert_eq!(color2.to_hex(), "#7F7F7F");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        self.to_hex_on(&Color(255, 255, 255, 1.0))
+    }
+
+    /// Convert the color to a hexadecimal string representation, like `to_hex`,
+    /// but if the alpha channel is not 1.0 it is blended against `background` instead of white.
+    /// The alpha of `background` is ignored.
+    ///
+    /// # Example
+    /// ``` rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+    /// let black = Color::from("#000").unwrap();
+    /// assert_eq!(color.to_hex_on(&black), "#7F0000");
+    /// ```
+    pub fn to_hex_on(&self, background: &Color) -> String {
+        let r = utils::calc_rgb_with_alpha_on(self.0, background.0, self.3) as u8;
+        let g = utils::calc_rgb_with_alpha_on(self.1, background.1, self.3) as u8;
+        let b = utils::calc_rgb_with_alpha_on(self.2, background.2, self.3) as u8;
+        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    }
+
+    /// Convert the color to a hexadecimal string with alpha representation.
+    /// ```
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+    /// assert_eq!(color.to_hex_alpha(), "#FF000080");
+    /// ```
+    pub fn to_hex_alpha(&self) -> String {
+        format!(
+            "#{:02X}{:02X}{:02X}{:02X}",
+            self.0,
+            self.1,
+            self.2,
+            self.alpha_u8()
+        )
+    }
+
+    /// Convert the color to the most compact hexadecimal CSS representation:
+    /// `#RGB` when every channel has two equal digits, `#RRGGBB` otherwise, and `#RRGGBBAA` if the alpha is not 1.0.
+    /// ```
+    /// use iColor::Color;
+    /// assert_eq!(Color::from("#FF0000").unwrap().to_css(), "#F00");
+    /// assert_eq!(Color::from("#FF00A0").unwrap().to_css(), "#FF00A0");
+    /// assert_eq!(Color::from("#FF000080").unwrap().to_css(), "#FF000080");
+    /// ```
+    pub fn to_css(&self) -> String {
+        if self.3 < 1.0 {
+            return self.to_hex_alpha();
+        }
+        let doubled = |v: u8| v >> 4 == v & 0x0F;
+        if doubled(self.0) && doubled(self.1) && doubled(self.2) {
+            format!("#{:X}{:X}{:X}", self.0 & 0x0F, self.1 & 0x0F, self.2 & 0x0F)
+        } else {
+            self.to_hex()
+        }
+    }
+
+    /// Convert the color to the format required by Excel, where the color format is usually #AARRGGBB, where AA is alpha
+    /// ```
+    /// use iColor::Color;
+    /// let color = Color::from("#FF0000").unwrap();
+    /// assert_eq!(color.to_alpha_hex(), "#FFFF0000");
     /// 
     /// let mut color2 = Color::from("#000").unwrap();
     /// color2.set_alpha(0.5);
-    /// assert_eq!(color2.to_alpha_hex(), "#7F000000");
+    /// assert_eq!(color2.to_alpha_hex(), "#80000000");
     /// ```
     pub fn to_alpha_hex(&self) -> String {
         format!(
             "#{:02X}{:02X}{:02X}{:02X}",
-            (self.3 * 255.0) as u8,
+            self.alpha_u8(),
             self.0,
             self.1,
             self.2
         )
     }
 
+    /// Pack the color into a `0xRRGGBBAA` integer, red is the most significant byte and alpha the least,
+    /// the alpha is rounded to 0 - 255.
+    /// ```
+    /// use iColor::Color;
+    /// let color = Color::from("#FF00AA").unwrap();
+    /// assert_eq!(color.to_u32(), 0xFF00AAFF);
+    /// ```
+    pub fn to_u32(&self) -> u32 {
+        u32::from_be_bytes([self.0, self.1, self.2, self.alpha_u8()])
+    }
+
+    /// Pack the red, green and blue of the color into a `0xRRGGBB` integer, the alpha is dropped.
+    /// ```
+    /// use iColor::Color;
+    /// let color = Color::from("#FF00AA80").unwrap();
+    /// assert_eq!(color.to_u32_rgb(), 0xFF00AA);
+    /// ```
+    pub fn to_u32_rgb(&self) -> u32 {
+        u32::from_be_bytes([0, self.0, self.1, self.2])
+    }
+
+    /// Pack the color into a `0xAARRGGBB` integer, alpha is the most significant byte,
+    /// the alpha is rounded to 0 - 255.
+    /// ```
+    /// use iColor::Color;
+    /// let color = Color::from("#FF00AA80").unwrap();
+    /// assert_eq!(color.to_u32_argb(), 0x80FF00AA);
+    /// ```
+    pub fn to_u32_argb(&self) -> u32 {
+        u32::from_be_bytes([self.alpha_u8(), self.0, self.1, self.2])
+    }
+
     /// Convert the color to a CSS RGB string representation.
     /// If the alpha channel is not 1.0, it will be computed with red, green, and blue.
     /// 
@@ -510,7 +1194,7 @@ impl Color {
         format!("rgb({},{},{})", r, g, b)
     }
 
-    /// Convert the color to a CSS RGBA string representation.
+    /// Convert the color to a CSS RGBA string representation, the alpha has up to 3 decimals with trailing zeros trimmed.
     /// 
     /// # Example
     /// ```rust
@@ -523,10 +1207,100 @@ impl Color {
     /// assert_eq!(color2.to_rgba(), "rgba(0,0,0,0.5)");
     /// ```
     pub fn to_rgba(&self) -> String {
-        format!("rgba({},{},{},{})", self.0, self.1, self.2, self.3)
+        format!("rgba({},{},{},{})", self.0, self.1, self.2, utils::format_alpha(self.3))
+    }
+
+    /// Get the red, green, and blue of the color, unlike `to_rgb` they are not computed with the alpha.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 170, 0.5).unwrap();
+    /// assert_eq!(color.to_rgb_tuple(), (255, 0, 170));
+    /// ```
+    pub fn to_rgb_tuple(&self) -> (u8, u8, u8) {
+        (self.0, self.1, self.2)
+    }
+
+    /// Get the red, green, blue, and alpha of the color.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 170, 0.5).unwrap();
+    /// assert_eq!(color.to_rgba_tuple(), (255, 0, 170, 0.5));
+    /// ```
+    pub fn to_rgba_tuple(&self) -> (u8, u8, u8, f32) {
+        (self.0, self.1, self.2, self.3)
+    }
+
+    /// Get the red, green, and blue of the color as an array, they are not computed with the alpha.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 170, 0.5).unwrap();
+    /// assert_eq!(color.to_rgb_array(), [255, 0, 170]);
+    /// ```
+    pub fn to_rgb_array(&self) -> [u8; 3] {
+        [self.0, self.1, self.2]
+    }
+
+    /// Get the red, green, blue, and alpha of the color as an array, the alpha is rounded to 0 - 255.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 170, 0.5).unwrap();
+    /// assert_eq!(color.to_rgba_array(), [255, 0, 170, 128]);
+    /// ```
+    pub fn to_rgba_array(&self) -> [u8; 4] {
+        self.to_u32().to_be_bytes()
+    }
+
+    /// Get the red, green, blue, and alpha of the color as an array with premultiplied alpha,
+    /// each channel is multiplied by the alpha, the alpha is rounded to 0 - 255.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 170, 0.5).unwrap();
+    /// assert_eq!(color.to_rgba_array_premultiplied(), [128, 0, 85, 128]);
+    /// ```
+    pub fn to_rgba_array_premultiplied(&self) -> [u8; 4] {
+        let premultiply = |v: u8| (v as f32 * self.3).round() as u8;
+        let [r, g, b, a] = self.to_rgba_array();
+        [premultiply(r), premultiply(g), premultiply(b), a]
+    }
+
+    /// Get the red, green, blue, and alpha of the color as an array for RGBA image buffers, the same as `to_rgba_array`.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 170, 0.5).unwrap();
+    /// assert_eq!(color.to_array(), [255, 0, 170, 128]);
+    /// ```
+    pub fn to_array(&self) -> [u8; 4] {
+        self.to_rgba_array()
+    }
+
+    /// Get the red, green, blue, and alpha of the color as an array with premultiplied alpha,
+    /// the same as `to_rgba_array_premultiplied`.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 170, 0.5).unwrap();
+    /// assert_eq!(color.to_array_premultiplied(), [128, 0, 85, 128]);
+    /// ```
+    pub fn to_array_premultiplied(&self) -> [u8; 4] {
+        self.to_rgba_array_premultiplied()
+    }
+
+    /// Get the red, green, blue, and alpha of the color normalized to 0.0 - 1.0, as shaders expect them.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from_rgba(255, 0, 51, 0.5).unwrap();
+    /// assert_eq!(color.to_rgba_f32_array(), [1.0, 0.0, 0.2, 0.5]);
+    /// ```
+    pub fn to_rgba_f32_array(&self) -> [f32; 4] {
+        [self.0 as f32 / 255.0, self.1 as f32 / 255.0, self.2 as f32 / 255.0, self.3]
     }
 
-    fn to_hsl_val(&self, with_alpha:bool) -> (u32, f32, f32) {
+    fn to_hsl_val(self, with_alpha:bool) -> (u32, f32, f32) {
+        let (h, s, l) = self.to_hsl_f32(with_alpha);
+        (h.round() as u32, s, l)
+    }
+
+    /// Like `to_hsl_val`, but the hue is not rounded to whole degrees.
+    fn to_hsl_f32(self, with_alpha:bool) -> (f32, f32, f32) {
         let (r, g, b) = if with_alpha {
             (
                 utils::calc_rgb_with_alpha(self.0, self.3) / 255.0,
@@ -544,29 +1318,40 @@ impl Color {
         let c_max = r.max(g).max(b);
         let c_min = r.min(g).min(b);
         let delta = c_max - c_min;
-        let mut h = if delta == 0.0 {
+        let h = if delta == 0.0 {
             0.0
         } else if c_max == r {
-            60.0 * ((g - b) / delta % 6.0)
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
         } else if c_max == g {
             60.0 * ((b - r) / delta + 2.0)
         } else {
             60.0 * ((r - g) / delta + 4.0)
         };
-        if h < 0.0 {
-            h += 360.0;
-        }
         let l = (c_max + c_min) / 2.0;
         let s = if delta == 0.0 {
             0.0
         } else {
             delta / (1.0 - (2.0 * l - 1.0).abs())
         };
-        (h.round() as u32, s, l)
+        (h, s, l)
+    }
+
+    /// Replace the red, green and blue channels with the given hsl, keeping the alpha.
+    /// The channels are rounded, so setting the hsl the color already has leaves it unchanged.
+    fn set_hsl(&mut self, h: f32, s: f32, l: f32) -> &mut Self {
+        if !(h.is_finite() && s.is_finite() && l.is_finite()) {
+            return self;
+        }
+        let (r, g, b) = utils::hsl_to_rgb(h.rem_euclid(360.0), s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+        self.0 = r.round() as u8;
+        self.1 = g.round() as u8;
+        self.2 = b.round() as u8;
+        self
     }
 
     /// Convert the color to a CSS HSL string representation.
-    /// If the alpha channel is not 1.0, it will be computed with red, green, and blue.
+    /// If the alpha channel is not 1.0, the color is first blended against a white background,
+    /// so the result is the opaque color it looks like, use `to_hsla` to keep the hsl and alpha apart.
     /// 
     /// # Example
     /// ```rust
@@ -583,7 +1368,9 @@ impl Color {
         format!("hsl({:.0},{:.0}%,{:.0}%)", h, s*100.0, l*100.0)
     }
 
-    /// Convert the color to a CSS HSLA string representation.A
+    /// Convert the color to a CSS HSLA string representation.
+    /// The hue, saturation and lightness are those of the unblended color, the alpha is reported on its own
+    /// with up to 3 decimals like `to_rgba`, a whole alpha keeps one decimal, ex: `1.0`.
     /// ```
     /// use iColor::Color;
     /// let color = Color::from("#FF0000").unwrap();
@@ -595,111 +1382,350 @@ impl Color {
     /// ```
     pub fn to_hsla(&self) -> String {
         let (h, s, l) = self.to_hsl_val(false);
-        format!("hsla({:.0},{:.0}%,{:.0}%,{:.1})", h, s*100.0, l*100.0, self.3)
+        format!("hsla({:.0},{:.0}%,{:.0}%,{})", h, s*100.0, l*100.0, utils::format_alpha_decimal(self.3))
     }
 
-    /// Convert the color to a CSS HSLA string representation.A
-    /// ```rust
-    /// use iColor::Color;
-    /// let color = Color::from("#FF0000").unwrap();
-    /// assert_eq!(color.to_hsv(), "hsv(0,100%,100%)");
-    /// 
-    /// let mut color2 = Color::from("#000").unwrap();
-    /// color2.set_alpha(0.5);
-    /// assert_eq!(color2.to_hsv(), "hsv(0,0%,50%)");
-    /// ```
-    pub fn to_hsv(&self) -> String {
-        let r = utils::calc_rgb_with_alpha(self.0, self.3) / 255.0;
-        let g = utils::calc_rgb_with_alpha(self.1, self.3) / 255.0;
-        let b = utils::calc_rgb_with_alpha(self.2, self.3) / 255.0;
+    fn to_hsv_val(self, with_alpha:bool) -> (u32, f32, f32) {
+        let (r, g, b) = if with_alpha {
+            (
+                utils::calc_rgb_with_alpha(self.0, self.3) / 255.0,
+                utils::calc_rgb_with_alpha(self.1, self.3) / 255.0,
+                utils::calc_rgb_with_alpha(self.2, self.3) / 255.0
+            )
+        } else {
+            (
+                self.0 as f32 / 255.0,
+                self.1 as f32 / 255.0,
+                self.2 as f32 / 255.0
+            )
+        };
 
         let c_max = r.max(g).max(b);
         let c_min = r.min(g).min(b);
         let delta = c_max - c_min;
 
-        let mut h = if delta == 0.0 {
+        let h = if delta == 0.0 {
             0.0
         } else if c_max == r {
-            60.0 * (((g - b) / delta) % 6.0)
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
         } else if c_max == g {
             60.0 * (((b - r) / delta) + 2.0)
         } else {
             60.0 * (((r - g) / delta) + 4.0)
         };
 
-        if h < 0.0 {
-            h += 360.0;
-        }
-
         let s = if c_max == 0.0 { 0.0 } else { delta / c_max };
 
         let v = c_max;
 
-        format!("hsv({:.0},{:.0}%,{:.0}%)", h, s*100.0, v*100.0)
+        (h.round() as u32, s, v)
     }
 
-    /// Convert the color to a CSS cmyk string representation.A
+    /// Convert the color to a HSV string representation.
+    /// If the alpha channel is not 1.0, it will be computed with red, green, and blue.
     /// ```rust
     /// use iColor::Color;
     /// let color = Color::from("#FF0000").unwrap();
-    /// assert_eq!(color.to_cmyk(), "cmyk(0,100,100,0)");
+    /// assert_eq!(color.to_hsv(), "hsv(0,100%,100%)");
     /// 
     /// let mut color2 = Color::from("#000").unwrap();
     /// color2.set_alpha(0.5);
-    /// assert_eq!(color2.to_cmyk(), "cmyk(0,0,0,50)");
+    /// assert_eq!(color2.to_hsv(), "hsv(0,0%,50%)");
     /// ```
-    pub fn to_cmyk(&self) -> String {
-        let r = utils::calc_rgb_with_alpha(self.0, self.3) / 255.0;
-        let g = utils::calc_rgb_with_alpha(self.1, self.3) / 255.0;
-        let b = utils::calc_rgb_with_alpha(self.2, self.3) / 255.0;
-
-        let k = 1.0 - r.max(g).max(b);
-        let (c,m,y) = if k == 1.0 { (0.0, 0.0, 0.0)} else {
-            (
-                (1.0 - r - k) / (1.0 - k),
-                (1.0 - g - k) / (1.0 - k),
-                (1.0 - b - k) / (1.0 - k)
-            )
-        };
-        format!("cmyk({:.0},{:.0},{:.0},{:.0})", c*100.0, m*100.0, y*100.0, k*100.0)
+    pub fn to_hsv(&self) -> String {
+        let (h, s, v) = self.to_hsv_val(true);
+        format!("hsv({:.0},{:.0}%,{:.0}%)", h, s*100.0, v*100.0)
     }
 
-    /// Set the alpha value of the color.
-    ///
-    /// # Arguments
-    ///
-    /// * `alpha` - A float value between 0.0 and 1.0 representing the alpha value of the color.
-    ///
-    /// # Example
-    ///
+    /// Convert the color to a HSVA string representation.
+    /// The hue, saturation and value are those of the unblended color, the alpha is reported on its own
+    /// with up to 3 decimals like `to_rgba`, a whole alpha keeps one decimal, ex: `1.0`.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from("#FF0000").unwrap();
+    /// assert_eq!(color.to_hsva(), "hsva(0,100%,100%,1.0)");
+    /// 
+    /// let mut color2 = Color::from("#000").unwrap();
+    /// color2.set_alpha(0.5);
+    /// assert_eq!(color2.to_hsva(), "hsva(0,0%,0%,0.5)");
     /// ```
+    pub fn to_hsva(&self) -> String {
+        let (h, s, v) = self.to_hsv_val(false);
+        format!("hsva({:.0},{:.0}%,{:.0}%,{})", h, s*100.0, v*100.0, utils::format_alpha_decimal(self.3))
+    }
+
+    /// Convert the color to a CSS HWB string representation.
+    /// If the alpha channel is not 1.0, it will be computed with red, green, and blue.
+    /// ```rust
     /// use iColor::Color;
-    ///
-    /// let mut color = Color::from("#000").unwrap();
-    /// color.set_alpha(0.5);
-    /// assert_eq!(color.to_hsl(), "hsl(0,0%,50%)");
+    /// let color = Color::from("#FF0000").unwrap();
+    /// assert_eq!(color.to_hwb(), "hwb(0 0% 0%)");
     /// 
-    pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
-        self.3 = alpha;
-        self
+    /// let color2 = Color::from("#3372B2").unwrap();
+    /// assert_eq!(color2.to_hwb(), "hwb(210 20% 30%)");
+    /// ```
+    pub fn to_hwb(&self) -> String {
+        let (h, s, v) = self.to_hsv_val(true);
+        format!("hwb({:.0} {:.0}% {:.0}%)", h, (1.0 - s) * v * 100.0, (1.0 - v) * 100.0)
     }
 
-    /// Determine whether the color is a dark color
-    pub fn is_dark(&self) -> bool {
-        let (_,_,l) = self.to_hsl_val(true);
-        l < 0.5
+    /// Convert the color to CIE XYZ `(x, y, z)` with the D65 white point, where Y of white is 1.0, the alpha is ignored.
+    /// ```rust
+    /// use iColor::Color;
+    /// let (x, y, z) = Color::from("#FFFFFF").unwrap().to_xyz();
+    /// assert!((x - 0.9505).abs() < 0.001 && (y - 1.0).abs() < 0.001 && (z - 1.089).abs() < 0.001);
+    /// ```
+    pub fn to_xyz(&self) -> (f32, f32, f32) {
+        utils::rgb_to_xyz(self.0, self.1, self.2)
     }
 
-    /// Determine whether the color is a light color
-    pub fn is_light(&self) -> bool {
-        !self.is_dark()
+    /// Convert the color to CIELAB `(l, a, b)`, through CIE XYZ with the D65 white point, the alpha is ignored.
+    /// ```rust
+    /// use iColor::Color;
+    /// let (l, a, b) = Color::from("#FFFFFF").unwrap().to_lab();
+    /// assert!((l - 100.0).abs() < 0.01 && a.abs() < 0.01 && b.abs() < 0.01);
+    /// ```
+    pub fn to_lab(&self) -> (f32, f32, f32) {
+        let (x, y, z) = self.to_xyz();
+        utils::xyz_to_lab(x, y, z)
     }
 
-    /// Inverts the color by subtracting each RGB component from 255 and inverting the alpha value.
-    pub fn negate(&mut self) -> &mut Self {
-        self.0 = 255 - self.0;
+    /// Convert the color to CIE LCh `(l, c, h)`, with the hue in degrees in 0.0 - 360.0, the alpha is ignored.
+    /// ```rust
+    /// use iColor::Color;
+    /// let (l, c, h) = Color::from("#FF0000").unwrap().to_lch();
+    /// assert!((l - 53.24).abs() < 0.05 && (c - 104.55).abs() < 0.05 && (h - 40.0).abs() < 0.05);
+    /// ```
+    pub fn to_lch(&self) -> (f32, f32, f32) {
+        let (l, a, b) = self.to_lab();
+        utils::lab_to_lch(l, a, b)
+    }
+
+    /// Convert the color to OKLab `(l, a, b)`, the alpha is ignored.
+    /// ```rust
+    /// use iColor::Color;
+    /// let (l, a, b) = Color::from("#FF0000").unwrap().to_oklab();
+    /// assert!((l - 0.628).abs() < 0.001 && (a - 0.2249).abs() < 0.001 && (b - 0.1258).abs() < 0.001);
+    /// ```
+    pub fn to_oklab(&self) -> (f32, f32, f32) {
+        utils::rgb_to_oklab(self.0, self.1, self.2)
+    }
+
+    /// Convert the color to OKLCH `(l, c, h)`, with the hue in degrees in 0.0 - 360.0, the alpha is ignored.
+    /// ```rust
+    /// use iColor::Color;
+    /// let (l, c, h) = Color::from("#FF0000").unwrap().to_oklch();
+    /// assert!((l - 0.628).abs() < 0.001 && (c - 0.2577).abs() < 0.001 && (h - 29.23).abs() < 0.05);
+    /// ```
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        let (l, a, b) = self.to_oklab();
+        utils::lab_to_lch(l, a, b)
+    }
+
+    /// Convert the color to a CSS cmyk string representation.
+    /// CMYK has no alpha channel, so there is no `cmyka` form, if the alpha channel is not 1.0,
+    /// the color is blended against a white background, the same as printing it on white paper.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from("#FF0000").unwrap();
+    /// assert_eq!(color.to_cmyk(), "cmyk(0,100,100,0)");
+    /// 
+    /// let mut color2 = Color::from("#000").unwrap();
+    /// color2.set_alpha(0.5);
+    /// assert_eq!(color2.to_cmyk(), "cmyk(0,0,0,50)");
+    /// ```
+    pub fn to_cmyk(&self) -> String {
+        let (c, m, y, k) = self.to_cmyk_val();
+        format!("cmyk({:.0},{:.0},{:.0},{:.0})", c*100.0, m*100.0, y*100.0, k*100.0)
+    }
+
+    /// Convert the color to a cmyk string representation with percent signs, as design tools usually show it.
+    /// If the alpha channel is not 1.0, it will be computed with red, green, and blue.
+    /// ```rust
+    /// use iColor::Color;
+    /// let color = Color::from("#FF0000").unwrap();
+    /// assert_eq!(color.to_cmyk_percent(), "cmyk(0%,100%,100%,0%)");
+    /// ```
+    pub fn to_cmyk_percent(&self) -> String {
+        let (c, m, y, k) = self.to_cmyk_val();
+        format!("cmyk({:.0}%,{:.0}%,{:.0}%,{:.0}%)", c*100.0, m*100.0, y*100.0, k*100.0)
+    }
+
+    fn to_cmyk_val(self) -> (f32, f32, f32, f32) {
+        let r = utils::calc_rgb_with_alpha(self.0, self.3) / 255.0;
+        let g = utils::calc_rgb_with_alpha(self.1, self.3) / 255.0;
+        let b = utils::calc_rgb_with_alpha(self.2, self.3) / 255.0;
+
+        let k = 1.0 - r.max(g).max(b);
+        let (c,m,y) = if k == 1.0 { (0.0, 0.0, 0.0)} else {
+            (
+                (1.0 - r - k) / (1.0 - k),
+                (1.0 - g - k) / (1.0 - k),
+                (1.0 - b - k) / (1.0 - k)
+            )
+        };
+        (c, m, y, k)
+    }
+
+    /// Get the red channel of the color, between in 0 - 255.
+    pub fn red(&self) -> u8 {
+        self.0
+    }
+
+    /// Get the green channel of the color, between in 0 - 255.
+    pub fn green(&self) -> u8 {
+        self.1
+    }
+
+    /// Get the blue channel of the color, between in 0 - 255.
+    pub fn blue(&self) -> u8 {
+        self.2
+    }
+
+    /// Get the alpha channel of the color, between in 0.0 - 1.0.
+    /// ```
+    /// use iColor::Color;
+    /// let color = Color::from("rgba(10,20,30,0.5)").unwrap();
+    /// assert_eq!((color.red(), color.green(), color.blue(), color.alpha()), (10, 20, 30, 0.5));
+    /// ```
+    pub fn alpha(&self) -> f32 {
+        self.3
+    }
+
+    /// Get the alpha channel of the color as a byte, rounded to 0 - 255,
+    /// the same byte `to_hex_alpha`, `to_u32` and `to_rgba_array` output.
+    /// ```
+    /// use iColor::Color;
+    /// let color = Color::from("rgba(10,20,30,0.5)").unwrap();
+    /// assert_eq!(color.alpha_u8(), 128);
+    /// assert_eq!(Color::from("#0A141E80").unwrap().alpha_u8(), 128);
+    /// ```
+    pub fn alpha_u8(&self) -> u8 {
+        (self.3 * 255.0).round() as u8
+    }
+
+    /// Determine whether the color is a gray, its red, green and blue are equal, the alpha is ignored.
+    /// ```
+    /// use iColor::Color;
+    /// assert!(Color::from("#808080").unwrap().is_grayscale());
+    /// assert!(!Color::from("#808081").unwrap().is_grayscale());
+    /// ```
+    pub fn is_grayscale(&self) -> bool {
+        self.is_grayscale_within(0)
+    }
+
+    /// Determine whether the color is a gray, its red, green and blue differ by no more than `tolerance`.
+    /// ```
+    /// use iColor::Color;
+    /// assert!(Color::from("#808081").unwrap().is_grayscale_within(1));
+    /// ```
+    pub fn is_grayscale_within(&self, tolerance: u8) -> bool {
+        let max = self.0.max(self.1).max(self.2);
+        let min = self.0.min(self.1).min(self.2);
+        max - min <= tolerance
+    }
+
+    /// Determine whether the color is fully opaque, its alpha is 1.0.
+    pub fn is_opaque(&self) -> bool {
+        self.3 >= 1.0
+    }
+
+    /// Determine whether the color is fully transparent, its alpha is 0.0.
+    pub fn is_transparent(&self) -> bool {
+        self.3 <= 0.0
+    }
+
+    /// Determine whether the color is translucent, its alpha is strictly between 0.0 and 1.0.
+    /// ```
+    /// use iColor::Color;
+    /// let color = Color::from("rgba(10,20,30,0.5)").unwrap();
+    /// assert!(color.is_translucent() && !color.is_opaque() && !color.is_transparent());
+    /// ```
+    pub fn is_translucent(&self) -> bool {
+        !self.is_opaque() && !self.is_transparent()
+    }
+
+    /// Set the alpha value of the color, values out of 0.0 - 1.0 are clamped into range and NaN becomes 1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - A float value between 0.0 and 1.0 representing the alpha value of the color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("#000").unwrap();
+    /// color.set_alpha(0.5);
+    /// assert_eq!(color.to_hsl(), "hsl(0,0%,50%)");
+    /// 
+    pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
+        self.3 = utils::clamp_alpha(alpha);
+        self
+    }
+
+    /// Get a copy of the color with the given alpha, clamped into 0.0 - 1.0, the color itself is unchanged.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let color = Color::from("#FF0000").unwrap();
+    /// assert_eq!(color.with_alpha(0.5).to_rgba(), "rgba(255,0,0,0.5)");
+    /// assert_eq!(color.alpha(), 1.0);
+    /// ```
+    pub fn with_alpha(&self, alpha: f32) -> Color {
+        let mut color = *self;
+        color.set_alpha(alpha);
+        color
+    }
+
+    /// Determine whether the color is a dark color, that is its relative luminance is below 0.179,
+    /// where white text has more contrast on it than black text.
+    /// If the alpha channel is not 1.0, it will be computed with red, green, and blue.
+    /// ```
+    /// use iColor::Color;
+    /// assert!(Color::from("#104C88").unwrap().is_dark());
+    /// assert!(!Color::from("#FFFF00").unwrap().is_dark());
+    /// ```
+    pub fn is_dark(&self) -> bool {
+        let r = utils::calc_rgb_with_alpha(self.0, self.3) as u8;
+        let g = utils::calc_rgb_with_alpha(self.1, self.3) as u8;
+        let b = utils::calc_rgb_with_alpha(self.2, self.3) as u8;
+        Color(r, g, b, 1.0).luminance() < 0.179
+    }
+
+    /// Determine whether the color is a light color, see `is_dark`.
+    pub fn is_light(&self) -> bool {
+        !self.is_dark()
+    }
+
+    /// Inverts the color by subtracting each RGB component from 255, the alpha is kept.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from_rgba(25, 125, 100, 0.3).unwrap();
+    /// color.negate();
+    /// assert_eq!(color.to_rgba(), "rgba(230,130,155,0.3)");
+    /// ```
+    pub fn negate(&mut self) -> &mut Self {
+        self.0 = 255 - self.0;
         self.1 = 255 - self.1;
         self.2 = 255 - self.2;
+        self
+    }
+
+    /// Inverts the alpha value, an opaque color becomes fully transparent and the other way round.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from_rgba(25, 125, 100, 0.25).unwrap();
+    /// color.negate_alpha();
+    /// assert_eq!(color.to_rgba(), "rgba(25,125,100,0.75)");
+    /// ```
+    pub fn negate_alpha(&mut self) -> &mut Self {
         self.3 = 1.0 - self.3;
         self
     }
@@ -719,7 +1745,7 @@ impl Color {
     /// assert_eq!(color.to_rgba(), "rgba(0,0,0,0.25)");
     /// ``` 
     pub fn fade(&mut self, ratio: f32) -> &mut Self {
-        let ratio = ratio.max(0.0).min(1.0);
+        let ratio = utils::clamp_ratio(ratio);
         self.3 = ((self.3 - self.3 * ratio) * 100.0).round() / 100.0;
         self
     }
@@ -739,59 +1765,2146 @@ impl Color {
     /// color.opaquer(0.5);
     /// assert_eq!(color.to_rgba(), "rgba(0,0,0,0.67)");
     /// ``` 
-
     pub fn opaquer(&mut self, ratio: f32) -> &mut Self {
-        let ratio = ratio.max(0.0).min(1.0);
+        let ratio = utils::clamp_ratio(ratio);
         self.3 = ((self.3 + self.3 * ratio).min(1.0) * 100.0).round() / 100.0;
         self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Increase the lightness of the color by a given ratio, in HSL space.
+    ///
+    /// # Arguments
+    /// * `ratio` - A float value between 0.0 and 1.0 added to the lightness, the lightness will not exceed 1.0.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("#104C88").unwrap();
+    /// color.lighten(0.2);
+    /// assert_eq!(color.to_hsl(), "hsl(210,79%,50%)");
+    /// ```
+    pub fn lighten(&mut self, ratio: f32) -> &mut Self {
+        let ratio = utils::clamp_ratio(ratio);
+        let (h, s, l) = self.to_hsl_f32(false);
+        self.set_hsl(h, s, l + ratio)
+    }
 
-    #[test]
-    fn it_works() {
-        assert!(Color::from("#ff00aa").is_ok());
-        assert!(Color::from("#f0a").is_ok());
-        assert!(Color::from("#ff00aa80").is_ok());
-        assert!(Color::from("rgb(129,45,78)").is_ok());
-        assert!(Color::from("rgba(129,45,78, 0.8)").is_ok());
-        assert!(Color::from("hsl(120, 45%, 90%)").is_ok());
-        assert!(Color::from("hsla(120, 45%, 90%, 0.5)").is_ok());
-        assert!(Color::from("hsv(120, 60%, 80%)").is_ok());
-        assert!(Color::from("cmyk(100, 40,70,90)").is_ok());
+    /// Decrease the lightness of the color by a given ratio, in HSL space.
+    ///
+    /// # Arguments
+    /// * `ratio` - A float value between 0.0 and 1.0 subtracted from the lightness, the lightness will not be less than 0.0.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("#FF0000").unwrap();
+    /// color.darken(0.2);
+    /// assert_eq!(color.to_hsl(), "hsl(0,100%,30%)");
+    /// ```
+    pub fn darken(&mut self, ratio: f32) -> &mut Self {
+        let ratio = utils::clamp_ratio(ratio);
+        let (h, s, l) = self.to_hsl_f32(false);
+        self.set_hsl(h, s, l - ratio)
+    }
 
-        assert!(Color::from("#zz00aa").is_err());
-        assert!(Color::from("#f0aa").is_err());
-        assert!(Color::from("#ff00aaZ0").is_err());
+    /// Increase the saturation of the color by a given ratio, in HSL space.
+    ///
+    /// # Arguments
+    /// * `ratio` - A float value between 0.0 and 1.0 added to the saturation, the saturation will not exceed 1.0.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("hsl(210,40%,50%)").unwrap();
+    /// color.saturate(0.2);
+    /// assert_eq!(color.to_hsl(), "hsl(210,60%,50%)");
+    /// ```
+    pub fn saturate(&mut self, ratio: f32) -> &mut Self {
+        let ratio = utils::clamp_ratio(ratio);
+        let (h, s, l) = self.to_hsl_f32(false);
+        self.set_hsl(h, s + ratio, l)
+    }
 
-        let mut color = Color::from("#ff00aa").unwrap();
-        assert_eq!(color.to_hex(), "#FF00AA");
-        assert_eq!(color.to_rgb(), "rgb(255,0,170)");
-        assert_eq!(color.to_rgba(), "rgba(255,0,170,1)");
-        assert_eq!(color.to_hex_alpha(), "#FF00AAFF");
-        assert_eq!(color.to_alpha_hex(), "#FFFF00AA");
-        assert_eq!(color.to_hsl(),"hsl(320,100%,50%)");
-        assert_eq!(color.to_hsla(),"hsla(320,100%,50%,1.0)");
-        assert_eq!(color.to_hsv(),"hsv(320,100%,100%)");
-        assert_eq!(color.to_cmyk(),"cmyk(0,100,33,0)");
+    /// Decrease the saturation of the color by a given ratio, in HSL space.
+    ///
+    /// # Arguments
+    /// * `ratio` - A float value between 0.0 and 1.0 subtracted from the saturation, the saturation will not be less than 0.0.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("hsl(210,50%,50%)").unwrap();
+    /// color.desaturate(0.2);
+    /// assert_eq!(color.to_hsl(), "hsl(210,31%,50%)");
+    /// ```
+    pub fn desaturate(&mut self, ratio: f32) -> &mut Self {
+        let ratio = utils::clamp_ratio(ratio);
+        let (h, s, l) = self.to_hsl_f32(false);
+        self.set_hsl(h, s - ratio, l)
+    }
 
-        color.set_alpha(0.5);
+    /// Mix the color toward white by a given ratio, in RGB space, the alpha is kept.
+    /// Unlike `lighten`, the hue drifts toward white instead of keeping the HSL saturation.
+    ///
+    /// # Arguments
+    /// * `ratio` - A float value between 0.0 and 1.0, 0.0 keeps the color and 1.0 gives white.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("#FF0000").unwrap();
+    /// color.tint(0.5);
+    /// assert_eq!(color.to_hex(), "#FF8080");
+    /// ```
+    pub fn tint(&mut self, ratio: f32) -> &mut Self {
+        *self = self.mix(&Color(255, 255, 255, self.3), ratio);
+        self
+    }
 
-        assert_eq!(color.to_hex(), "#FF7FD4");
-        assert_eq!(color.to_rgba(), "rgba(255,0,170,0.5)");
+    /// Mix the color toward black by a given ratio, in RGB space, the alpha is kept.
+    ///
+    /// # Arguments
+    /// * `ratio` - A float value between 0.0 and 1.0, 0.0 keeps the color and 1.0 gives black.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("#FF0000").unwrap();
+    /// color.shade(0.5);
+    /// assert_eq!(color.to_hex(), "#800000");
+    /// ```
+    pub fn shade(&mut self, ratio: f32) -> &mut Self {
+        *self = self.mix(&Color(0, 0, 0, self.3), ratio);
+        self
+    }
 
-        assert!(!color.is_dark());
-        assert!(color.is_light());
+    /// Brighten the color by adding `amount` of 255 to each of red, green and blue, in RGB space,
+    /// the channels saturate at 255 and the alpha is kept.
+    /// Unlike `lighten` and `tint`, the difference between the channels is kept until they saturate.
+    ///
+    /// # Arguments
+    /// * `amount` - A float value between 0.0 and 1.0, 1.0 always gives white.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("#000").unwrap();
+    /// color.brighten(0.2);
+    /// assert_eq!(color.to_hex(), "#333333");
+    /// ```
+    pub fn brighten(&mut self, amount: f32) -> &mut Self {
+        let add = (amount.clamp(0.0, 1.0) * 255.0).round() as u8;
+        self.0 = self.0.saturating_add(add);
+        self.1 = self.1.saturating_add(add);
+        self.2 = self.2.saturating_add(add);
+        self
+    }
 
-        color.fade(0.5);
-        assert_eq!(color.to_hex(), "#FFBFE9");
+    /// Rotate the hue of the color by the given degrees, in HSL space. Unlike `rotate_hue`, the hue is rounded to whole degrees,
+    /// so repeated spins do not drift.
+    /// The resulting hue is normalized into 0 - 360, so `spin(-30)` on a hue of 10 gives 340, and `spin(370)` is `spin(10)`.
+    ///
+    /// # Arguments
+    /// * `degrees` - The degrees to rotate, negative values rotate backwards, the hue wraps around 360.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("#FF0000").unwrap();
+    /// color.spin(120);
+    /// assert_eq!(color.to_hex(), "#00FF00");
+    ///
+    /// let mut color = Color::from("hsl(10,100%,50%)").unwrap();
+    /// color.spin(-30);
+    /// assert_eq!(color.to_hsl(), "hsl(340,100%,50%)");
+    /// ```
+    pub fn spin(&mut self, degrees: i32) -> &mut Self {
+        let (h, s, l) = self.to_hsl_val(false);
+        self.set_hsl((h as i64 + degrees as i64).rem_euclid(360) as f32, s, l)
+    }
 
-        color.opaquer(0.8);
-        assert_eq!(color.to_hex(), "#FF8CD8");
-        
+    /// Rotate the hue of the color by the given degrees, in HSL space.
+    /// The hue is not rounded to whole degrees, only the resulting channels are rounded.
+    ///
+    /// # Arguments
+    /// * `degrees` - The degrees to rotate, negative values rotate backwards, the hue wraps around 360.
+    ///   A NaN or infinite value leaves the color unchanged.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("#FF0000").unwrap();
+    /// color.rotate_hue(180.0);
+    /// assert_eq!(color.to_hex(), "#00FFFF");
+    /// ```
+    pub fn rotate_hue(&mut self, degrees: f32) -> &mut Self {
+        if !degrees.is_finite() {
+            return self;
+        }
+        let (h, s, l) = self.to_hsl_f32(false);
+        self.set_hsl(h + degrees, s, l)
     }
-}
 
+    /// Get the complement of the color, the hue rotated by 180 degrees on the color wheel.
+    /// Unlike `negate`, saturation and lightness are kept.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let color = Color::from("#FF0000").unwrap();
+    /// assert_eq!(color.complement().to_hex(), "#00FFFF");
+    /// ```
+    pub fn complement(&self) -> Color {
+        let mut color = *self;
+        color.spin(180);
+        color
+    }
+
+    /// Get the complementary color, the hue rotated by 180 degrees, the same as `complement`.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let color = Color::from("#FF0000").unwrap();
+    /// assert_eq!(color.complementary().to_hex(), "#00FFFF");
+    /// ```
+    pub fn complementary(&self) -> Color {
+        self.complement()
+    }
+
+    /// Get the triadic colors, the color itself and its hue rotated by 120 and 240 degrees.
+    /// Saturation, lightness and alpha are kept.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let [red, green, blue] = Color::from("#FF0000").unwrap().triadic();
+    /// assert_eq!((red.to_hex(), green.to_hex(), blue.to_hex()), ("#FF0000".into(), "#00FF00".into(), "#0000FF".into()));
+    /// ```
+    pub fn triadic(&self) -> [Color; 3] {
+        let mut second = *self;
+        let mut third = *self;
+        second.rotate_hue(120.0);
+        third.rotate_hue(240.0);
+        [*self, second, third]
+    }
+
+    /// Get the tetradic (square) colors, the color itself and its hue rotated by 90, 180 and 270 degrees.
+    /// Saturation, lightness and alpha are kept.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let colors = Color::from("#FF0000").unwrap().tetradic();
+    /// let hex: Vec<String> = colors.iter().map(|c| c.to_hex()).collect();
+    /// assert_eq!(hex, ["#FF0000", "#80FF00", "#00FFFF", "#8000FF"]);
+    /// ```
+    pub fn tetradic(&self) -> [Color; 4] {
+        let mut colors = [*self; 4];
+        for (i, color) in colors.iter_mut().enumerate().skip(1) {
+            color.rotate_hue(90.0 * i as f32);
+        }
+        colors
+    }
+
+    /// Get `count` analogous colors, spread `angle` degrees apart and centered on the hue of the color,
+    /// ordered by hue offset. An odd `count` includes the color itself in the middle.
+    /// Saturation, lightness and alpha are kept.
+    ///
+    /// # Arguments
+    /// * `count` - The number of colors, 0 returns an empty vector and 1 returns the color itself.
+    /// * `angle` - The degrees between two neighboring colors.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let colors = Color::from("#FF0000").unwrap().analogous(3, 30.0);
+    /// assert_eq!(colors[0].to_hsl(), "hsl(330,100%,50%)");
+    /// assert_eq!(colors[1].to_hsl(), "hsl(0,100%,50%)");
+    /// assert_eq!(colors[2].to_hsl(), "hsl(30,100%,50%)");
+    /// ```
+    pub fn analogous(&self, count: usize, angle: f32) -> Vec<Color> {
+        let center = count.saturating_sub(1) as f32 / 2.0;
+        (0..count)
+            .map(|i| {
+                let mut color = *self;
+                color.rotate_hue((i as f32 - center) * angle);
+                color
+            })
+            .collect()
+    }
+
+    /// Get the three analogous colors, the hue rotated by -30 degrees, the color itself and the hue rotated by 30 degrees,
+    /// the same as `analogous(3, 30.0)` as an array. Saturation, lightness and alpha are kept.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let [left, red, right] = Color::from("#FF0000").unwrap().analogous3();
+    /// assert_eq!((left.to_hsl(), red.to_hsl(), right.to_hsl()), ("hsl(330,100%,50%)".into(), "hsl(0,100%,50%)".into(), "hsl(30,100%,50%)".into()));
+    /// ```
+    pub fn analogous3(&self) -> [Color; 3] {
+        let mut left = *self;
+        let mut right = *self;
+        left.rotate_hue(-30.0);
+        right.rotate_hue(30.0);
+        [left, *self, right]
+    }
+
+    /// Convert the color to gray using the perceptual luminance (0.299R + 0.587G + 0.114B), keeping the alpha.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut color = Color::from("#FF0000").unwrap();
+    /// color.grayscale();
+    /// assert_eq!(color.to_hex(), "#4C4C4C");
+    /// ```
+    pub fn grayscale(&mut self) -> &mut Self {
+        let gray = (0.299 * self.0 as f32 + 0.587 * self.1 as f32 + 0.114 * self.2 as f32).round() as u8;
+        self.0 = gray;
+        self.1 = gray;
+        self.2 = gray;
+        self
+    }
+
+    /// Mix the color with another color, interpolating red, green, blue and alpha linearly.
+    ///
+    /// # Arguments
+    /// * `other` - The color to mix with.
+    /// * `weight` - A float value between 0.0 and 1.0, 0.0 returns this color and 1.0 returns `other`.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let black = Color::from("#000").unwrap();
+    /// let white = Color::from("#fff").unwrap();
+    /// assert_eq!(black.mix(&white, 0.5).to_hex(), "#808080");
+    /// ```
+    pub fn mix(&self, other: &Color, weight: f32) -> Color {
+        let weight = utils::clamp_ratio(weight);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * weight).round() as u8;
+        Color(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+            self.3 + (other.3 - self.3) * weight,
+        )
+    }
+
+    /// Generate `steps` evenly spaced colors from this color to `other`, both included, interpolating in RGB like `mix`.
+    /// With `steps` below 2 only the two endpoints are returned.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let black = Color::from("#000").unwrap();
+    /// let white = Color::from("#fff").unwrap();
+    /// let ramp: Vec<String> = black.gradient(&white, 3).iter().map(|c| c.to_hex()).collect();
+    /// assert_eq!(ramp, ["#000000", "#808080", "#FFFFFF"]);
+    /// ```
+    pub fn gradient(&self, other: &Color, steps: usize) -> Vec<Color> {
+        let steps = steps.max(2);
+        (0..steps).map(|i| self.mix(other, i as f32 / (steps - 1) as f32)).collect()
+    }
+
+    /// Generate `steps` evenly spaced colors from this color to `other`, both included, interpolating in CIELAB
+    /// for smoother perceptual transitions, the alpha is interpolated linearly.
+    /// With `steps` below 2 only the two endpoints are returned.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let blue = Color::from("#00f").unwrap();
+    /// let yellow = Color::from("#ff0").unwrap();
+    /// let ramp = blue.gradient_lab(&yellow, 3);
+    /// assert_eq!((ramp[0], ramp[2]), (blue, yellow));
+    /// assert_ne!(ramp[1], blue.gradient(&yellow, 3)[1]);
+    /// ```
+    pub fn gradient_lab(&self, other: &Color, steps: usize) -> Vec<Color> {
+        let steps = steps.max(2);
+        let (l1, a1, b1) = self.to_lab();
+        let (l2, a2, b2) = other.to_lab();
+        (0..steps)
+            .map(|i| match i {
+                0 => *self,
+                i if i == steps - 1 => *other,
+                i => {
+                    let t = i as f32 / (steps - 1) as f32;
+                    let mix = |a: f32, b: f32| a + (b - a) * t;
+                    let (x, y, z) = utils::lab_to_xyz(mix(l1, l2), mix(a1, a2), mix(b1, b2));
+                    let (r, g, b) = utils::xyz_to_rgb(x, y, z);
+                    Color(r, g, b, mix(self.3, other.3))
+                }
+            })
+            .collect()
+    }
+
+    /// Mix the color with another color in the perceptually uniform OKLab space, the alpha is interpolated linearly.
+    /// Unlike `mix`, the midpoint of two complementary colors does not dip to a muddy gray.
+    ///
+    /// # Arguments
+    /// * `other` - The color to mix with.
+    /// * `weight` - A float value between 0.0 and 1.0, 0.0 returns this color and 1.0 returns `other`.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let black = Color::from("#000").unwrap();
+    /// let white = Color::from("#fff").unwrap();
+    /// assert_eq!(black.mix_oklab(&white, 0.5).to_hex(), "#636363");
+    /// ```
+    pub fn mix_oklab(&self, other: &Color, weight: f32) -> Color {
+        let weight = utils::clamp_ratio(weight);
+        let (l1, a1, b1) = self.to_oklab();
+        let (l2, a2, b2) = other.to_oklab();
+        let mix = |a: f32, b: f32| a + (b - a) * weight;
+        let (r, g, b) = utils::oklab_to_rgb(mix(l1, l2), mix(a1, a2), mix(b1, b2));
+        Color(r, g, b, mix(self.3, other.3))
+    }
+
+    /// Get the WCAG relative luminance of the color, between in 0.0 - 1.0, the alpha is ignored.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// assert_eq!(Color::from("#fff").unwrap().luminance(), 1.0);
+    /// assert_eq!(Color::from("#000").unwrap().luminance(), 0.0);
+    /// ```
+    pub fn luminance(&self) -> f32 {
+        0.2126 * utils::linearize(self.0) + 0.7152 * utils::linearize(self.1) + 0.0722 * utils::linearize(self.2)
+    }
+
+    /// Compare two colors by their relative luminance, darker colors are less.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    /// use std::cmp::Ordering;
+    ///
+    /// let black = Color::from("#000").unwrap();
+    /// let white = Color::from("#fff").unwrap();
+    /// assert_eq!(black.luminance_cmp(&white), Ordering::Less);
+    /// ```
+    pub fn luminance_cmp(&self, other: &Color) -> Ordering {
+        self.luminance().total_cmp(&other.luminance())
+    }
+
+    /// Sort colors from the darkest to the lightest by relative luminance, see `luminance_cmp`.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let mut colors = [Color::from("#fff").unwrap(), Color::from("#000").unwrap()];
+    /// Color::sort_by_luminance(&mut colors);
+    /// assert_eq!(colors[0].to_hex(), "#000000");
+    /// ```
+    pub fn sort_by_luminance(colors: &mut [Color]) {
+        colors.sort_by(Color::luminance_cmp);
+    }
+
+    /// Get the WCAG contrast ratio between the color and another color, between in 1.0 - 21.0.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let black = Color::from("#000").unwrap();
+    /// let white = Color::from("#fff").unwrap();
+    /// assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.001);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let (l1, l2) = (self.luminance(), other.luminance());
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    /// Determine whether text in this color on the `background` meets the WCAG contrast requirement.
+    ///
+    /// # Arguments
+    /// * `background` - The background color.
+    /// * `level` - The WCAG level to meet, `WcagLevel::AA` or `WcagLevel::AAA`.
+    /// * `large_text` - Whether the text is large, large text has a lower threshold.
+    /// # Example
+    /// ```
+    /// use iColor::{Color, WcagLevel};
+    ///
+    /// let white = Color::from("#fff").unwrap();
+    /// let black = Color::from("#000").unwrap();
+    /// assert!(white.is_accessible(&black, WcagLevel::AAA, false));
+    /// ```
+    pub fn is_accessible(&self, background: &Color, level: WcagLevel, large_text: bool) -> bool {
+        let threshold = match (level, large_text) {
+            (WcagLevel::AA, false) => 4.5,
+            (WcagLevel::AA, true) => 3.0,
+            (WcagLevel::AAA, false) => 7.0,
+            (WcagLevel::AAA, true) => 4.5,
+        };
+        self.contrast_ratio(background) >= threshold
+    }
+
+    /// Get the CSS color name that exactly matches the red, green and blue of the color, the alpha is ignored.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// assert_eq!(Color::from("#FF0000").unwrap().to_named(), Some("red"));
+    /// assert_eq!(Color::from("#FF0001").unwrap().to_named(), None);
+    /// ```
+    pub fn to_named(&self) -> Option<&'static str> {
+        named::find(self.0, self.1, self.2)
+    }
+
+    /// Get the CSS color name closest to the color by Euclidean distance in RGB space, the alpha is ignored.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// assert_eq!(Color::from("#FE0101").unwrap().nearest_named(), "red");
+    /// ```
+    pub fn nearest_named(&self) -> &'static str {
+        named::nearest(self.0, self.1, self.2)
+    }
+
+    /// Composite the color over a `background` with Porter-Duff source-over.
+    /// The resulting alpha is `a + a_bg * (1 - a)`, so the result is opaque when the background is,
+    /// which is what `to_hex` and `to_rgb` do against a white background.
+    /// If both colors are fully transparent, the result is transparent black.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let shadow = Color::from_rgba(0, 0, 0, 0.5).unwrap();
+    /// let white = Color::from("#fff").unwrap();
+    /// assert_eq!(shadow.over(&white).to_hex(), "#7F7F7F");
+    ///
+    /// let glass = Color::from_rgba(255, 255, 255, 0.5).unwrap();
+    /// assert_eq!(shadow.over(&glass).to_rgba(), "rgba(85,85,85,0.75)");
+    /// ```
+    pub fn over(&self, background: &Color) -> Color {
+        let alpha = self.3 + background.3 * (1.0 - self.3);
+        if alpha == 0.0 {
+            return Color(0, 0, 0, 0.0);
+        }
+        let blend = |v: u8, bg: u8| ((v as f32 * self.3 + bg as f32 * background.3 * (1.0 - self.3)) / alpha) as u8;
+        Color(
+            blend(self.0, background.0),
+            blend(self.1, background.1),
+            blend(self.2, background.2),
+            alpha,
+        )
+    }
+
+    /// Get the Euclidean distance between two colors in RGB space, between in 0.0 - 441.67, the alpha is ignored.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let black = Color::from("#000").unwrap();
+    /// let red = Color::from("#f00").unwrap();
+    /// assert_eq!(black.distance(&red), 255.0);
+    /// ```
+    pub fn distance(&self, other: &Color) -> f32 {
+        let d = |a: u8, b: u8| (a as f32 - b as f32).powi(2);
+        (d(self.0, other.0) + d(self.1, other.1) + d(self.2, other.2)).sqrt()
+    }
+
+    /// Get the perceptual distance between two colors, the CIE76 delta E, that is the Euclidean distance in CIELAB.
+    /// A delta E around 2.3 is just noticeable, the alpha is ignored.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let black = Color::from("#000").unwrap();
+    /// let white = Color::from("#fff").unwrap();
+    /// assert!((black.distance_cie76(&white) - 100.0).abs() < 0.01);
+    /// ```
+    pub fn distance_cie76(&self, other: &Color) -> f32 {
+        let (l1, a1, b1) = self.to_lab();
+        let (l2, a2, b2) = other.to_lab();
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// Determine whether two colors are equal within a tolerance, each of red, green and blue may differ by up to `tolerance`,
+    /// and the alpha by less than half a step of the 0 - 255 scale.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let color = Color::from("#104C88").unwrap();
+    /// assert!(color.approx_eq(&Color::from("#114C87").unwrap(), 1));
+    /// assert!(!color.approx_eq(&Color::from("#114C87").unwrap(), 0));
+    /// ```
+    pub fn approx_eq(&self, other: &Color, tolerance: u8) -> bool {
+        self.0.abs_diff(other.0) <= tolerance
+            && self.1.abs_diff(other.1) <= tolerance
+            && self.2.abs_diff(other.2) <= tolerance
+            && (self.3 - other.3).abs() < 0.5 / 255.0
+    }
+
+    /// Start building a color channel by channel, see `ColorBuilder`.
+    /// # Example
+    /// ```
+    /// use iColor::Color;
+    ///
+    /// let color = Color::builder().red(255).blue(170).alpha(0.5).build();
+    /// assert_eq!(color.to_rgba(), "rgba(255,0,170,0.5)");
+    /// ```
+    pub fn builder() -> ColorBuilder {
+        ColorBuilder::default()
+    }
+}
+
+impl ColorBuilder {
+    /// Set the red channel.
+    pub fn red(mut self, red: u8) -> Self {
+        self.red = red;
+        self
+    }
+
+    /// Set the green channel.
+    pub fn green(mut self, green: u8) -> Self {
+        self.green = green;
+        self
+    }
+
+    /// Set the blue channel.
+    pub fn blue(mut self, blue: u8) -> Self {
+        self.blue = blue;
+        self
+    }
+
+    /// Set the alpha, it is clamped into 0.0 - 1.0 when the color is built.
+    pub fn alpha(mut self, alpha: f32) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Build the color.
+    pub fn build(&self) -> Color {
+        Color::from_rgba_clamped(self.red, self.green, self.blue, self.alpha)
+    }
+}
+
+/// An opaque black builder.
+impl Default for ColorBuilder {
+    fn default() -> Self {
+        ColorBuilder { red: 0, green: 0, blue: 0, alpha: 1.0 }
+    }
+}
+
+/// Formats the color as `#RRGGBB`, or `#RRGGBBAA` when the alpha is below 1.0.
+/// ```
+/// use iColor::Color;
+/// let color = Color::from("#ff0000").unwrap();
+/// assert_eq!(format!("{}", color), "#FF0000");
+///
+/// let color2 = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+/// assert_eq!(color2.to_string(), "#FF000080");
+/// ```
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.3 < 1.0 {
+            f.write_str(&self.to_hex_alpha())
+        } else {
+            f.write_str(&self.to_hex())
+        }
+    }
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorError::Format { input } => write!(f, "invalid color format `{}`", input),
+            ColorError::Value => f.write_str("color value out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ColorError {}
+
+/// Parse a color with `str::parse`, accepts the same formats as `Color::from`.
+/// ```
+/// use iColor::Color;
+/// let color: Color = "#ff00aa".parse().unwrap();
+/// assert_eq!(color.to_hex(), "#FF00AA");
+/// ```
+impl FromStr for Color {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::from(s)
+    }
+}
+
+/// The default color is opaque black, the same as the CSS initial color.
+/// ```
+/// use iColor::Color;
+/// assert_eq!(Color::default().to_rgba(), "rgba(0,0,0,1)");
+/// ```
+impl Default for Color {
+    fn default() -> Self {
+        Color(0, 0, 0, 1.0)
+    }
+}
+
+/// Colors are compared by their stored channels, every constructor and setter keeps the alpha out of NaN.
+impl Eq for Color {}
+
+/// The alpha is hashed quantized to a 0 - 255 byte, so equal colors always hash equally.
+impl Hash for Color {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+        self.2.hash(state);
+        self.alpha_u8().hash(state);
+    }
+}
+
+/// Create an opaque color from `(red, green, blue)`.
+/// ```
+/// use iColor::Color;
+/// let color: Color = (255, 0, 170).into();
+/// assert_eq!(color.to_hex(), "#FF00AA");
+/// ```
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Color(r, g, b, 1.0)
+    }
+}
+
+/// Create a color from `(red, green, blue, alpha)`, the alpha is clamped into 0.0 - 1.0.
+impl From<(u8, u8, u8, f32)> for Color {
+    fn from((r, g, b, a): (u8, u8, u8, f32)) -> Self {
+        Color::from_rgba_clamped(r, g, b, a)
+    }
+}
+
+/// Convert a color into `(red, green, blue, alpha)`.
+impl From<Color> for (u8, u8, u8, f32) {
+    fn from(color: Color) -> Self {
+        color.to_rgba_tuple()
+    }
+}
+
+/// Create an opaque color from an `[red, green, blue]` pixel.
+impl From<[u8; 3]> for Color {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Color(r, g, b, 1.0)
+    }
+}
+
+/// Create a color from an `[red, green, blue, alpha]` pixel, the alpha byte is scaled into 0.0 - 1.0.
+/// ```
+/// use iColor::Color;
+/// let color: Color = [255, 0, 170, 255].into();
+/// assert_eq!(color.to_hex(), "#FF00AA");
+/// ```
+impl From<[u8; 4]> for Color {
+    fn from(rgba: [u8; 4]) -> Self {
+        Color::from_u32(u32::from_be_bytes(rgba))
+    }
+}
+
+/// Create a color from a packed `0xRRGGBBAA` integer, see `Color::from_u32`.
+impl From<u32> for Color {
+    fn from(value: u32) -> Self {
+        Color::from_u32(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        assert!(Color::from("#ff00aa").is_ok());
+        assert!(Color::from("#f0a").is_ok());
+        assert!(Color::from("#ff00aa80").is_ok());
+        assert!(Color::from("rgb(129,45,78)").is_ok());
+        assert!(Color::from("rgba(129,45,78, 0.8)").is_ok());
+        assert!(Color::from("hsl(120, 45%, 90%)").is_ok());
+        assert!(Color::from("hsla(120, 45%, 90%, 0.5)").is_ok());
+        assert!(Color::from("hsv(120, 60%, 80%)").is_ok());
+        assert!(Color::from("cmyk(100, 40,70,90)").is_ok());
+
+        assert!(Color::from("#zz00aa").is_err());
+        assert!(Color::from("#f0aaa").is_err());
+        assert!(Color::from("#ff00aaZ0").is_err());
+
+        let mut color = Color::from("#ff00aa").unwrap();
+        assert_eq!(color.to_hex(), "#FF00AA");
+        assert_eq!(color.to_rgb(), "rgb(255,0,170)");
+        assert_eq!(color.to_rgba(), "rgba(255,0,170,1)");
+        assert_eq!(color.to_hex_alpha(), "#FF00AAFF");
+        assert_eq!(color.to_alpha_hex(), "#FFFF00AA");
+        assert_eq!(color.to_hsl(),"hsl(320,100%,50%)");
+        assert_eq!(color.to_hsla(),"hsla(320,100%,50%,1.0)");
+        assert_eq!(color.to_hsv(),"hsv(320,100%,100%)");
+        assert_eq!(color.to_cmyk(),"cmyk(0,100,33,0)");
+
+        color.set_alpha(0.5);
+
+        assert_eq!(color.to_hex(), "#FF7FD4");
+        assert_eq!(color.to_rgba(), "rgba(255,0,170,0.5)");
+
+        assert!(!color.is_dark());
+        assert!(color.is_light());
+
+        color.fade(0.5);
+        assert_eq!(color.to_hex(), "#FFBFE9");
+
+        color.opaquer(0.8);
+        assert_eq!(color.to_hex(), "#FF8CD8");
+        
+    }
+
+    #[test]
+    fn hex_alpha() {
+        let color = Color::from("#FF000080").unwrap();
+        assert!((color.3 - 0.5).abs() < 0.01);
+        assert_eq!(color.to_hex_alpha(), "#FF000080");
+        assert_eq!(Color::from("#FF0000FF").unwrap().3, 1.0);
+        assert_eq!(Color::from("#FF000000").unwrap().3, 0.0);
+
+        let color = Color::from("#FF00AA80").unwrap();
+        assert!((color.3 - 0.5).abs() < 0.01);
+        assert_eq!(color.to_hex_alpha(), "#FF00AA80");
+    }
+
+    #[test]
+    fn rgba_alpha() {
+        assert_eq!(Color::from("rgba(129,45,78,0.8)").unwrap().to_rgba(), "rgba(129,45,78,0.8)");
+        assert_eq!(Color::from("rgba(129,45,78,1)").unwrap().to_rgba(), "rgba(129,45,78,1)");
+        assert_eq!(Color::from("rgba(129,45,78,0)").unwrap().to_rgba(), "rgba(129,45,78,0)");
+        assert!(matches!(Color::from("rgba(129,45,78,1.5)"), Err(ColorError::Value)));
+
+        let color = Color::from("rgba(10,20,30,0.8)").unwrap();
+        assert_eq!(color, Color(10, 20, 30, 0.8));
+    }
+
+    #[test]
+    fn hsla_alpha() {
+        assert_eq!(Color::from("hsla(120,45%,90%,1)").unwrap().3, 1.0);
+        assert_eq!(Color::from("hsla(120,45%,90%,1.0)").unwrap().3, 1.0);
+        assert_eq!(Color::from("hsla(120,45%,90%,0)").unwrap().3, 0.0);
+        assert_eq!(Color::from("hsla(120,45%,90%,0.0)").unwrap().3, 0.0);
+        assert_eq!(Color::from("hsla(120,45%,90%,.5)").unwrap().3, 0.5);
+        assert_eq!(Color::from("hsla(120,45%,90%,0.33)").unwrap().3, 0.33);
+        assert_eq!(Color::from("hsla(120,45%,90%,1)").unwrap().to_hex(), Color::from("hsl(120,45%,90%)").unwrap().to_hex());
+        assert!(matches!(Color::from("hsla(120,45%,90%,1.5)"), Err(ColorError::Value)));
+        assert!(matches!(Color::from("hsla(120,45%,90%,.)"), Err(ColorError::Format { .. })));
+    }
+
+    #[test]
+    fn hue_360() {
+        assert_eq!(Color::from_hsl(360, 1.0, 0.5).unwrap(), Color::from_hsl(0, 1.0, 0.5).unwrap());
+        assert_eq!(Color::from_hsv(360, 1.0, 1.0).unwrap(), Color::from_hsv(0, 1.0, 1.0).unwrap());
+        assert_eq!(Color::from_hsla(360, 1.0, 0.5, 0.5).unwrap(), Color::from_hsla(0, 1.0, 0.5, 0.5).unwrap());
+        assert_eq!(Color::from("hsl(360,100%,50%)").unwrap().to_hex(), "#FF0000");
+        assert_eq!(Color::from_hsl(480, 1.0, 0.5).unwrap(), Color::from_hsl(120, 1.0, 0.5).unwrap());
+        assert_eq!(Color::from_hsv(720, 1.0, 1.0).unwrap(), Color::from_hsv(0, 1.0, 1.0).unwrap());
+        assert_eq!(Color::from("hsl(400,100%,50%)").unwrap(), Color::from("hsl(40,100%,50%)").unwrap());
+    }
+
+    #[test]
+    fn invalid_hex() {
+        assert!(matches!(Color::from("#gg00aa"), Err(ColorError::Format { .. })));
+        assert!(matches!(Color::from("#ff_0aa"), Err(ColorError::Format { .. })));
+        assert!(matches!(Color::from("#f_a"), Err(ColorError::Format { .. })));
+        assert!(matches!(Color::from("#ff00aa_0"), Err(ColorError::Format { .. })));
+        assert_eq!(Color::from("#Ff00aA").unwrap(), Color(255, 0, 170, 1.0));
+    }
+
+    #[test]
+    fn rgb_out_of_range() {
+        assert!(matches!(Color::from("rgb(256,0,0)"), Err(ColorError::Value)));
+        assert!(matches!(Color::from("rgba(0,0,300,0.5)"), Err(ColorError::Value)));
+        assert!(matches!(Color::from("rgb(xx,0,0)"), Err(ColorError::Format { .. })));
+        assert!(matches!(Color::from("rgb(300,0,0)"), Err(ColorError::Value)));
+        assert!(matches!(Color::from("rgb(abc,0,0)"), Err(ColorError::Format { .. })));
+        assert!(matches!(Color::from("rgba(0,999,0,1)"), Err(ColorError::Value)));
+        assert_eq!(Color::from("rgb(255,0,0)").unwrap(), Color(255, 0, 0, 1.0));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Color::from("#ff0000").unwrap()), "#FF0000");
+        assert_eq!(format!("{}", Color::from("#ff000080").unwrap()), "#FF000080");
+        assert_eq!(Color::from("rgba(255,0,170,0)").unwrap().to_string(), "#FF00AA00");
+    }
+
+    #[test]
+    fn lighten() {
+        let mut color = Color::from("#104C88").unwrap();
+        color.lighten(0.2);
+        assert_eq!(color.to_hsl(), "hsl(210,79%,50%)");
+        color.lighten(1.0);
+        assert_eq!(color.to_hex(), "#FFFFFF");
+
+        let mut color2 = Color::from_rgba(16, 76, 136, 0.5).unwrap();
+        color2.lighten(0.2);
+        assert_eq!(color2.3, 0.5);
+        assert_eq!(color2.to_hsla(), "hsla(210,79%,50%,0.5)");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("rgb(1,2,3)".parse::<Color>().unwrap(), Color(1, 2, 3, 1.0));
+        assert!(matches!("rgb(1,2)".parse::<Color>(), Err(ColorError::Format { .. })));
+    }
+
+    #[test]
+    fn named() {
+        assert_eq!(Color::from("red").unwrap(), Color(255, 0, 0, 1.0));
+        assert_eq!(Color::from("CornflowerBlue").unwrap().to_hex(), "#6495ED");
+        assert_eq!(Color::from("rebeccapurple").unwrap().to_hex(), "#663399");
+        assert_eq!(Color::from_name("aliceblue").unwrap().to_hex(), "#F0F8FF");
+        assert_eq!(Color::from_name("YellowGreen").unwrap().to_hex(), "#9ACD32");
+        assert_eq!(Color::from("transparent").unwrap(), Color(0, 0, 0, 0.0));
+        assert!(matches!(Color::from("notacolor"), Err(ColorError::Format { .. })));
+        assert!(matches!(Color::from_name(""), Err(ColorError::Format { .. })));
+
+        assert_eq!(Color::from("red").unwrap(), Color::from("#FF0000").unwrap());
+        assert_eq!(Color::from("RED").unwrap(), Color::from("#FF0000").unwrap());
+        assert_eq!(Color::from("Navy").unwrap(), Color::from("#000080").unwrap());
+        assert_eq!(Color::from("grey").unwrap(), Color::from("gray").unwrap());
+        assert_eq!(Color::from("Transparent").unwrap().to_rgba(), "rgba(0,0,0,0)");
+    }
+
+    #[test]
+    fn getters() {
+        let color = Color::from_rgba(10, 20, 30, 0.5).unwrap();
+        assert_eq!(color.red(), 10);
+        assert_eq!(color.green(), 20);
+        assert_eq!(color.blue(), 30);
+        assert_eq!(color.alpha(), 0.5);
+    }
+
+    #[test]
+    fn saturate() {
+        let mut color = Color::from("#7A8085").unwrap();
+        let (_, s, _) = color.to_hsl_val(false);
+        color.saturate(0.3);
+        assert!(color.to_hsl_val(false).1 > s + 0.25);
+
+        let mut color2 = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+        color2.desaturate(1.0);
+        assert_eq!(color2.to_hsla(), "hsla(0,0%,50%,0.5)");
+        assert_eq!(color2.0, color2.1);
+        assert_eq!(color2.1, color2.2);
+
+        // a NaN ratio leaves the color unchanged
+        let base = Color::from("#3366CC").unwrap();
+        let mut color3 = base;
+        color3.lighten(f32::NAN).darken(f32::NAN).saturate(f32::NAN).desaturate(f32::NAN);
+        assert_eq!(color3, base);
+    }
+
+    #[test]
+    fn grayscale() {
+        let mut color = Color::from("#FF0000").unwrap();
+        color.grayscale();
+        assert_eq!(color.to_hex(), "#4C4C4C");
+        assert!(color.is_dark());
+
+        let mut color2 = Color::from_rgba(255, 255, 0, 0.5).unwrap();
+        color2.grayscale();
+        assert_eq!(color2, Color(226, 226, 226, 0.5));
+        assert!(color2.is_light());
+
+        for hex in ["#104C88", "#FF00AA", "#123456", "#FFFFFF"] {
+            let mut gray = Color::from(hex).unwrap();
+            gray.grayscale();
+            assert!(gray.0 == gray.1 && gray.1 == gray.2);
+        }
+    }
+
+    #[test]
+    fn darken() {
+        let mut color = Color::from("#808080").unwrap();
+        let (_, _, l) = color.to_hsl_val(false);
+        color.darken(0.25);
+        assert!((color.to_hsl_val(false).2 - l / 2.0).abs() < 0.01);
+        color.darken(1.0);
+        assert_eq!(color.to_hex(), "#000000");
+
+        let mut color2 = Color::from_rgba(16, 76, 136, 0.5).unwrap();
+        color2.darken(0.1);
+        assert_eq!(color2.3, 0.5);
+        assert_eq!(color2.to_hsl_val(false).0, 210);
+        assert!((color2.to_hsl_val(false).2 - 0.2).abs() < 0.01);
+    }
+
+    #[test]
+    fn spin() {
+        let mut color = Color::from("#FF0000").unwrap();
+        color.spin(120);
+        assert_eq!(color.to_hex(), "#00FF00");
+
+        let mut color2 = Color::from("#FF0000").unwrap();
+        color2.spin(-120);
+        assert_eq!(color2.to_hex(), "#0000FF");
+
+        let mut color3 = Color::from_rgba(16, 76, 136, 0.5).unwrap();
+        let mut color4 = color3;
+        color3.spin(-30);
+        color4.spin(330);
+        assert_eq!(color3, color4);
+        assert_eq!(color3.3, 0.5);
+        assert_eq!(color3.to_hsl_val(false).0, 180);
+    }
+
+    #[test]
+    fn mix() {
+        let black = Color::from("#000").unwrap();
+        let white = Color::from("#fff").unwrap();
+        assert_eq!(black.mix(&white, 0.5), Color(128, 128, 128, 1.0));
+        assert_eq!(black.mix(&white, 0.0), black);
+        assert_eq!(black.mix(&white, 1.0), white);
+        assert_eq!(black.mix(&white, 2.0), white);
+
+        let red = Color::from_rgba(255, 0, 0, 0.0).unwrap();
+        assert_eq!(red.mix(&white, 0.5), Color(255, 128, 128, 0.5));
+    }
+
+    #[test]
+    fn complement() {
+        let red = Color::from("hsl(0,100%,50%)").unwrap();
+        assert_eq!(red.complement().to_hsl(), "hsl(180,100%,50%)");
+
+        let color = Color::from_rgba(16, 76, 136, 0.5).unwrap();
+        let complement = color.complement();
+        assert_eq!(complement.3, 0.5);
+        assert_eq!(complement.to_hsl_val(false).0, 30);
+        assert_eq!(color, Color(16, 76, 136, 0.5));
+    }
+
+    #[test]
+    fn complementary() {
+        let red = Color::from_hsla(0, 1.0, 0.5, 0.4).unwrap();
+        let complementary = red.complementary();
+        assert_eq!(complementary.to_hsl_val(false).0, 180);
+        assert_eq!(complementary.3, 0.4);
+        assert_eq!(complementary, red.complement());
+    }
+
+    #[test]
+    fn luminance() {
+        assert!((Color::from("#fff").unwrap().luminance() - 1.0).abs() < 1e-6);
+        assert_eq!(Color::from("#000").unwrap().luminance(), 0.0);
+        let gray = Color::from("#808080").unwrap().luminance();
+        assert!((gray - 0.2159).abs() < 0.001);
+        assert!((Color::from("#FF0000").unwrap().luminance() - 0.2126).abs() < 1e-6);
+        assert_eq!(Color::from_rgba(255, 0, 0, 0.1).unwrap().luminance(), Color::from("#FF0000").unwrap().luminance());
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        let black = Color::from("#000").unwrap();
+        let white = Color::from("#fff").unwrap();
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-4);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 1e-4);
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+        let gray = Color::from("#767676").unwrap();
+        assert!(gray.contrast_ratio(&white) > 4.5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let color = Color::from("#ff00aa").unwrap();
+        assert_eq!(serde_json::to_string(&color).unwrap(), r##""#FF00AA""##);
+        let color2 = Color::from("#ff00aa80").unwrap();
+        assert_eq!(serde_json::to_string(&color2).unwrap(), r##""#FF00AA80""##);
+        assert_eq!(serde_json::from_str::<Color>(r##""#FF00AA""##).unwrap(), color);
+        assert_eq!(serde_json::from_str::<Color>(r#""rgb(255, 0, 170)""#).unwrap(), color);
+        assert_eq!(serde_json::from_str::<Color>(&serde_json::to_string(&color2).unwrap()).unwrap(), color2);
+
+        let err = serde_json::from_str::<Color>(r#""nope""#).unwrap_err();
+        assert!(err.to_string().contains("invalid color `nope`"));
+    }
+
+    #[test]
+    fn is_accessible() {
+        let white = Color::from("#fff").unwrap();
+        let black = Color::from("#000").unwrap();
+        assert!(white.is_accessible(&black, WcagLevel::AAA, false));
+        assert!(black.is_accessible(&white, WcagLevel::AA, false));
+
+        let light_gray = Color::from("#cccccc").unwrap();
+        assert!(!light_gray.is_accessible(&white, WcagLevel::AA, false));
+        assert!(!light_gray.is_accessible(&white, WcagLevel::AA, true));
+
+        // contrast ratio of #767676 on white is about 4.54
+        let gray = Color::from("#767676").unwrap();
+        assert!(gray.is_accessible(&white, WcagLevel::AA, false));
+        assert!(gray.is_accessible(&white, WcagLevel::AAA, true));
+        assert!(!gray.is_accessible(&white, WcagLevel::AAA, false));
+    }
+
+    #[test]
+    fn from_tuple() {
+        let color: Color = (255, 0, 170).into();
+        assert_eq!(color, Color(255, 0, 170, 1.0));
+        let color2: Color = (255, 0, 170, 0.5).into();
+        assert_eq!(color2, Color(255, 0, 170, 0.5));
+        let tuple: (u8, u8, u8, f32) = color2.into();
+        assert_eq!(tuple, (255, 0, 170, 0.5));
+        let color3: Color = tuple.into();
+        assert_eq!(color3, color2);
+
+        let opaque: Color = (255, 0, 170, 1.5).into();
+        assert_eq!(opaque.3, 1.0);
+        let transparent: Color = (255, 0, 170, -0.5).into();
+        assert_eq!(transparent.3, 0.0);
+    }
+
+    #[test]
+    fn hash_set() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(Color::from("#ff0000").unwrap());
+        set.insert(Color::from("red").unwrap());
+        set.insert(Color::from("rgb(255,0,0)").unwrap());
+        set.insert(Color::from("rgba(255,0,0,0.5)").unwrap());
+        set.insert(Color::from("#00ff00").unwrap());
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&Color(255, 0, 0, 0.5)));
+    }
+
+    #[test]
+    fn nan_alpha_never_stored() {
+        const NAN: Color = Color::rgba(255, 0, 0, f32::NAN);
+        assert_eq!(NAN, NAN);
+        assert_eq!(NAN.alpha(), 1.0);
+        assert_eq!(Color::rgba(255, 0, 0, 1.5).alpha(), 1.0);
+        assert_eq!(Color::from_rgba(255, 0, 0, f32::NAN).unwrap_err(), ColorError::Value);
+
+        let mut color = Color::rgba(255, 0, 0, 0.5);
+        color.fade(f32::NAN).opaquer(f32::NAN);
+        assert_eq!(color.alpha(), 0.5);
+        let blue = Color::rgb(0, 0, 255);
+        assert_eq!(color.mix(&blue, f32::NAN), color);
+        assert_eq!(color.mix_oklab(&blue, f32::NAN).alpha(), 0.5);
+    }
+
+    #[test]
+    fn hsva() {
+        let color = Color::from("hsva(120,60%,80%,0.5)").unwrap();
+        assert_eq!(color.3, 0.5);
+        assert_eq!(color.to_hsva(), "hsva(120,60%,80%,0.5)");
+        assert_eq!(Color::from(&color.to_hsva()).unwrap(), color);
+
+        let quarter = Color::from("hsva(120,60%,80%,0.25)").unwrap();
+        assert_eq!(quarter.to_hsva(), "hsva(120,60%,80%,0.25)");
+        assert_eq!(Color::from(&quarter.to_hsva()).unwrap(), quarter);
+        let quarter = Color::from("hsla(240,100%,50%,0.25)").unwrap();
+        assert_eq!(quarter.to_hsla(), "hsla(240,100%,50%,0.25)");
+        assert_eq!(Color::from(&quarter.to_hsla()).unwrap(), quarter);
+        // a whole alpha keeps the one decimal form
+        assert_eq!(quarter.with_alpha(1.0).to_hsla(), "hsla(240,100%,50%,1.0)");
+        assert_eq!(quarter.with_alpha(0.0).to_hsva(), "hsva(240,100%,100%,0.0)");
+
+        assert_eq!(Color::from("hsva(120, 60%, 80%, 1)").unwrap(), Color::from("hsv(120,60%,80%)").unwrap());
+        assert!(matches!(Color::from("hsva(120,60%,80%,2)"), Err(ColorError::Value)));
+        assert!(matches!(Color::from("hsva(120,60%,80%)"), Err(ColorError::Format { .. })));
+    }
+
+    #[test]
+    fn to_named() {
+        assert_eq!(Color::from("#FF0000").unwrap().to_named(), Some("red"));
+        assert_eq!(Color::from("#663399").unwrap().to_named(), Some("rebeccapurple"));
+        assert_eq!(Color::from("#00FFFF").unwrap().to_named(), Some("aqua"));
+        assert_eq!(Color::from("#FF0001").unwrap().to_named(), None);
+
+        assert_eq!(Color::from("#FF0000").unwrap().nearest_named(), "red");
+        assert_eq!(Color::from("#F80A05").unwrap().nearest_named(), "red");
+        assert_eq!(Color::from("#010101").unwrap().nearest_named(), "black");
+    }
+
+    #[test]
+    fn is_dark() {
+        assert!(Color::from("#000").unwrap().is_dark());
+        assert!(Color::from("#fff").unwrap().is_light());
+        // hsl lightness 50%, but perceptually light
+        assert!(Color::from("#FFFF00").unwrap().is_light());
+        assert!(Color::from("#0000FF").unwrap().is_dark());
+        assert!(Color::from_rgba(0, 0, 0, 0.1).unwrap().is_light());
+    }
+
+    #[test]
+    fn to_tuple() {
+        let color = Color::from_rgba(16, 76, 136, 0.5).unwrap();
+        assert_eq!(color.to_rgb_tuple(), (16, 76, 136));
+        assert_eq!(color.to_rgba_tuple(), (16, 76, 136, 0.5));
+        assert_eq!(Color::from_rgb(1, 2, 3).unwrap().to_rgba_tuple(), (1, 2, 3, 1.0));
+    }
+
+    #[test]
+    fn short_hex_alpha() {
+        let color = Color::from("#F00A").unwrap();
+        assert_eq!(color.to_rgb_tuple(), (255, 0, 0));
+        assert!((color.3 - 0.667).abs() < 0.001);
+        assert_eq!(color, Color::from("#FF0000AA").unwrap());
+        assert!(matches!(Color::from("#F00G"), Err(ColorError::Format { .. })));
+        assert_eq!(Color::from("#F0A8").unwrap(), Color::from("#FF00AA88").unwrap());
+        assert_eq!(Color::from("#f0a8").unwrap().to_hex_alpha(), "#FF00AA88");
+    }
+
+    #[test]
+    fn packed_u32() {
+        assert_eq!(Color::from_u32(0xFF00AA80), Color(255, 0, 170, 128.0 / 255.0));
+        assert_eq!(Color::from_rgba(255, 0, 170, 0.5).unwrap().to_u32(), 0xFF00AA80);
+        assert_eq!(Color::from_rgba(1, 2, 3, 0.0).unwrap().to_u32(), 0x01020300);
+        for value in [0x00000000, 0xFFFFFFFF, 0x12345678, 0xFF00AA80] {
+            assert_eq!(Color::from_u32(value).to_u32(), value);
+        }
+    }
+
+    #[test]
+    fn cmyk_percent() {
+        let color = Color::from("#FF00AA").unwrap();
+        assert_eq!(color.to_cmyk_percent(), "cmyk(0%,100%,33%,0%)");
+        assert_eq!(Color::from("cmyk(0%,100%,100%,0%)").unwrap().to_hex(), "#FF0000");
+        assert_eq!(Color::from("cmyk(0%, 100%, 100%, 0%)").unwrap(), Color::from("cmyk(0,100,100,0)").unwrap());
+        let red = Color::from("#FF0000").unwrap();
+        assert_eq!(Color::from(&red.to_cmyk_percent()).unwrap(), red);
+        assert_eq!(Color::from(&red.to_cmyk()).unwrap(), red);
+    }
+
+    #[test]
+    fn hsl_adjustments_round_trip() {
+        let base = Color::from("#3366CC").unwrap();
+        let mut color = base;
+        color.lighten(0.0);
+        assert_eq!(color.to_hex(), "#3366CC");
+        color.spin(0);
+        assert_eq!(color.to_hex(), "#3366CC");
+        color.rotate_hue(0.0).darken(0.0).saturate(0.0).desaturate(0.0);
+        assert_eq!(color, base);
+        assert_eq!(base.analogous(1, 30.0)[0], base);
+
+        // repeated rotations come back to the start instead of drifting
+        for _ in 0..12 {
+            color.spin(30);
+        }
+        assert_eq!(color, base);
+    }
+
+    #[test]
+    fn rotate_hue() {
+        let mut color = Color::from("#FF0000").unwrap();
+        color.rotate_hue(120.0);
+        assert_eq!(color.to_hex(), "#00FF00");
+
+        let mut color2 = Color::from("#FF0000").unwrap();
+        color2.rotate_hue(180.0);
+        assert_eq!(color2.to_hex(), "#00FFFF");
+
+        let mut color3 = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+        color3.rotate_hue(-90.0);
+        assert_eq!(color3.to_hsla(), "hsla(270,100%,50%,0.5)");
+
+        let mut color4 = Color::from("#FF0000").unwrap();
+        color4.rotate_hue(360.0);
+        assert_eq!(color4.to_hex(), "#FF0000");
+        color4.rotate_hue(359.7);
+        assert_eq!(color4.to_hex(), "#FF0001");
+
+        // fractional degrees accumulate instead of being rounded away
+        let mut color5 = Color::from("#FF0000").unwrap();
+        color5.rotate_hue(0.4);
+        assert_eq!(color5.to_hex(), "#FF0200");
+
+        let base = Color::from("hsl(220,60%,50%)").unwrap();
+        for degrees in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let mut color = base;
+            color.rotate_hue(degrees);
+            assert_eq!(color, base);
+        }
+    }
+
+    #[test]
+    fn triadic() {
+        let red = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+        let hues: Vec<u32> = red.triadic().iter().map(|c| c.to_hsl_val(false).0).collect();
+        assert_eq!(hues, [0, 120, 240]);
+        assert!(red.triadic().iter().all(|c| c.3 == 0.5));
+        assert_eq!(red.triadic()[0], red);
+        assert_eq!(red.complement().to_hsl_val(false).0, 180);
+    }
+
+    #[test]
+    fn rgb_percent() {
+        assert_eq!(Color::from("rgb(100%,0%,50%)").unwrap(), Color(255, 0, 128, 1.0));
+        assert_eq!(Color::from("rgb(100%, 100%, 100%)").unwrap().to_hex(), "#FFFFFF");
+        assert!(matches!(Color::from("rgb(100%,0,50%)"), Err(ColorError::Format { .. })));
+        assert!(matches!(Color::from("rgb(101%,0%,50%)"), Err(ColorError::Value)));
+    }
+
+    #[test]
+    fn analogous() {
+        let color = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+        assert!(color.analogous(0, 30.0).is_empty());
+        assert_eq!(color.analogous(1, 30.0), vec![color]);
+
+        let colors = color.analogous(5, 20.0);
+        let hues: Vec<u32> = colors.iter().map(|c| c.to_hsl_val(false).0).collect();
+        assert_eq!(hues, [320, 340, 0, 20, 40]);
+        for c in &colors {
+            let (_, s, l) = c.to_hsl_val(false);
+            assert!((s - 1.0).abs() < 0.01 && (l - 0.5).abs() < 0.01);
+            assert_eq!(c.3, 0.5);
+        }
+
+        let hues: Vec<u32> = color.analogous(2, 30.0).iter().map(|c| c.to_hsl_val(false).0).collect();
+        assert_eq!(hues, [345, 15]);
+    }
+
+    #[test]
+    fn analogous3() {
+        let color = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+        let colors = color.analogous3();
+        let hsl: Vec<String> = colors.iter().map(|c| c.to_hsla()).collect();
+        assert_eq!(hsl, ["hsla(330,100%,50%,0.5)", "hsla(0,100%,50%,0.5)", "hsla(30,100%,50%,0.5)"]);
+        assert_eq!(colors.to_vec(), color.analogous(3, 30.0));
+    }
+
+    #[test]
+    fn hue_units() {
+        let cyan = Color::from("hsl(180,100%,50%)").unwrap();
+        assert_eq!(Color::from("hsl(0.5turn,100%,50%)").unwrap(), cyan);
+        assert_eq!(Color::from("hsl(200grad,100%,50%)").unwrap(), cyan);
+        assert_eq!(Color::from("hsl(3.14159rad,100%,50%)").unwrap(), cyan);
+        assert_eq!(Color::from("hsl(180deg,100%,50%)").unwrap(), cyan);
+        assert_eq!(Color::from("hsl(-180,100%,50%)").unwrap(), cyan);
+        assert_eq!(Color::from("hsla(0.5turn, 100%, 50%, 0.5)").unwrap(), Color::from("hsla(180,100%,50%,0.5)").unwrap());
+        assert_eq!(Color::from("hsv(0.5turn,100%,100%)").unwrap(), Color::from("hsv(180,100%,100%)").unwrap());
+        assert_eq!(Color::from("hsva(200grad,100%,100%,1)").unwrap(), Color::from("hsv(180,100%,100%)").unwrap());
+        assert!(matches!(Color::from("hsl(180foo,100%,50%)"), Err(ColorError::Format { .. })));
+    }
+
+    #[test]
+    fn packed_u32_rgb_argb() {
+        assert_eq!(Color::from_u32_rgb(0xFF00AA).to_hex(), "#FF00AA");
+        assert_eq!(Color::from_u32_rgb(0x12FF00AA), Color(255, 0, 170, 1.0));
+        assert_eq!(Color::from_u32_rgb(0xFF00AA).to_u32_rgb(), 0xFF00AA);
+
+        let color = Color::from_u32_argb(0x80FF00AA);
+        assert_eq!(color.to_rgb_tuple(), (255, 0, 170));
+        assert!((color.3 - 0.5).abs() < 0.01);
+        for value in [0x00000000, 0xFFFFFFFF, 0x80FF00AA, 0x12345678] {
+            assert_eq!(Color::from_u32_argb(value).to_u32_argb(), value);
+        }
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(ColorError::format("#ff").to_string(), "invalid color format `#ff`");
+        assert_eq!(ColorError::Value.to_string(), "color value out of range");
+        assert_eq!(Color::from("rgb(300,0,0)").unwrap_err().to_string(), "color value out of range");
+    }
+
+    #[test]
+    fn to_css() {
+        assert_eq!(Color::from("#FF0000").unwrap().to_css(), "#F00");
+        assert_eq!(Color::from("#112233").unwrap().to_css(), "#123");
+        assert_eq!(Color::from("#000000").unwrap().to_css(), "#000");
+        assert_eq!(Color::from("#FF00A0").unwrap().to_css(), "#FF00A0");
+        assert_eq!(Color::from("#104C88").unwrap().to_css(), "#104C88");
+        assert_eq!(Color::from("#FF000080").unwrap().to_css(), "#FF000080");
+        assert_eq!(Color::from("#F008").unwrap().to_css(), "#FF000088");
+        let color = Color::from("#abc").unwrap();
+        assert_eq!(Color::from(&color.to_css()).unwrap(), color);
+    }
+
+    #[test]
+    fn whitespace_and_case() {
+        let red = Color(255, 0, 0, 1.0);
+        assert_eq!(Color::from(" #FF0000").unwrap(), red);
+        assert_eq!(Color::from("#ff0000 ").unwrap(), red);
+        assert_eq!(Color::from("\t#F00\n").unwrap(), red);
+        assert_eq!(Color::from("RGB(255, 0, 0)").unwrap(), red);
+        assert_eq!(Color::from("rgb(\t255 ,\t0 ,0 )").unwrap(), red);
+        assert_eq!(Color::from("  Rgba( 255 , 0 , 0 , 1 ) ").unwrap(), red);
+        assert_eq!(Color::from("HSL(0, 100 %, 50%)").unwrap(), red);
+        assert_eq!(Color::from(" Red ").unwrap(), red);
+        assert!(matches!(Color::from("rgb(2 55,0,0)"), Err(ColorError::Format { .. })));
+    }
+
+    #[test]
+    fn error_input() {
+        assert_eq!(Color::from(" Not A Color ").unwrap_err(), ColorError::Format { input: " Not A Color ".to_string() });
+        assert_eq!(Color::from("#zz00aa").unwrap_err(), ColorError::format("#zz00aa"));
+        assert_eq!(Color::from_rgb_str("rgb(1,2)").unwrap_err(), ColorError::format("rgb(1,2)"));
+        assert_eq!(Color::from_hex("#12").unwrap_err(), ColorError::format("#12"));
+        assert_eq!(Color::from("rgb(300,0,0)").unwrap_err(), ColorError::Value);
+    }
+
+    #[test]
+    fn non_hex_digits() {
+        for input in ["#zz00aa", "#12345g", "#GGHHII", "#12_456", "#12g", "#12_", "#123g", "#1234567g"] {
+            assert_eq!(Color::from(input).unwrap_err(), ColorError::format(input), "{input}");
+        }
+        assert_eq!(Color::from_hex("#12345g").unwrap_err(), ColorError::format("#12345g"));
+        assert_eq!(Color::from_hex_alpha("#123456_8").unwrap_err(), ColorError::format("#123456_8"));
+        assert_eq!(Color::from_short_hex_alpha("#12_4").unwrap_err(), ColorError::format("#12_4"));
+    }
+
+    #[test]
+    fn lab() {
+        let close = |(l1, a1, b1): (f32, f32, f32), (l2, a2, b2): (f32, f32, f32)| {
+            (l1 - l2).abs() < 0.05 && (a1 - a2).abs() < 0.05 && (b1 - b2).abs() < 0.05
+        };
+        assert!(close(Color::from("#FFFFFF").unwrap().to_lab(), (100.0, 0.0, 0.0)));
+        assert!(close(Color::from("#000000").unwrap().to_lab(), (0.0, 0.0, 0.0)));
+        assert!(close(Color::from("#FF0000").unwrap().to_lab(), (53.24, 80.09, 67.20)));
+        assert!(close(Color::from("#808080").unwrap().to_lab(), (53.59, 0.0, 0.0)));
+
+        assert_eq!(Color::from_lab(100.0, 0.0, 0.0).unwrap().to_hex(), "#FFFFFF");
+        assert_eq!(Color::from_lab(0.0, 0.0, 0.0).unwrap().to_hex(), "#000000");
+        assert_eq!(Color::from_lab(53.24, 80.09, 67.2).unwrap().to_hex(), "#FF0000");
+        for hex in ["#104C88", "#FF00AA", "#123456", "#FEDCBA"] {
+            let color = Color::from(hex).unwrap();
+            let (l, a, b) = color.to_lab();
+            assert_eq!(Color::from_lab(l, a, b).unwrap(), color);
+        }
+
+        assert_eq!(Color::from_lab(101.0, 0.0, 0.0).unwrap_err(), ColorError::Value);
+        assert_eq!(Color::from_lab(50.0, -200.0, 0.0).unwrap_err(), ColorError::Value);
+    }
+
+    #[test]
+    fn const_constructors() {
+        const BRAND: Color = Color::rgb(16, 76, 136);
+        const OVERLAY: Color = Color::rgba(16, 76, 136, 0.5);
+        static PALETTE: [Color; 2] = [BRAND, OVERLAY];
+        assert_eq!(BRAND, Color::from_rgb(16, 76, 136).unwrap());
+        assert_eq!(OVERLAY, Color::from_rgba(16, 76, 136, 0.5).unwrap());
+        assert_eq!(PALETTE[1].3, 0.5);
+
+        const RED: Color = Color::rgb_const(255, 0, 0);
+        const TRANSLUCENT: Color = Color::rgba_const(255, 0, 0, 0.25);
+        assert_eq!(RED.to_hex(), "#FF0000");
+        assert_eq!(TRANSLUCENT.to_rgba(), "rgba(255,0,0,0.25)");
+        assert_eq!(RED.with_alpha(0.25), TRANSLUCENT);
+    }
+
+    #[test]
+    fn over() {
+        let shadow = Color::from_rgba(0, 0, 0, 0.5).unwrap();
+        let white = Color::from("#fff").unwrap();
+        let red = Color::from("#f00").unwrap();
+        assert_eq!(shadow.over(&white), Color(127, 127, 127, 1.0));
+        assert_eq!(shadow.over(&red), Color(127, 0, 0, 1.0));
+        assert_eq!(red.over(&white), red);
+        assert_eq!(Color::from_rgba(0, 0, 255, 0.0).unwrap().over(&red), red);
+
+        let color = Color::from_rgba(255, 0, 170, 0.3).unwrap();
+        assert_eq!(color.over(&white).to_hex(), color.to_hex());
+    }
+
+    #[test]
+    fn xyz() {
+        let (x, y, z) = Color::from("#FF0000").unwrap().to_xyz();
+        assert!((x - 0.4125).abs() < 0.001 && (y - 0.2127).abs() < 0.001 && (z - 0.0193).abs() < 0.001);
+        assert_eq!(Color::from("#000").unwrap().to_xyz(), (0.0, 0.0, 0.0));
+
+        for hex in ["#FFFFFF", "#000000", "#104C88", "#FF00AA", "#7F7F7F", "#00FF00"] {
+            let color = Color::from(hex).unwrap();
+            let (x, y, z) = color.to_xyz();
+            let back = Color::from_xyz(x, y, z).unwrap();
+            assert!(color.0.abs_diff(back.0) <= 1 && color.1.abs_diff(back.1) <= 1 && color.2.abs_diff(back.2) <= 1);
+        }
+
+        assert_eq!(Color::from_xyz(-0.1, 0.0, 0.0).unwrap_err(), ColorError::Value);
+        assert_eq!(Color::from_xyz(f32::NAN, 0.0, 0.0).unwrap_err(), ColorError::Value);
+    }
+
+    #[test]
+    fn hwb() {
+        assert_eq!(Color::from_hwb(0, 0.0, 0.0).unwrap().to_hex(), "#FF0000");
+        assert_eq!(Color::from_hwb(120, 0.0, 0.0).unwrap().to_hex(), "#00FF00");
+        assert_eq!(Color::from_hwb(240, 0.0, 0.0).unwrap().to_hex(), "#0000FF");
+        assert_eq!(Color::from_hwb(0, 1.0, 0.0).unwrap().to_hex(), "#FFFFFF");
+        assert_eq!(Color::from_hwb(0, 0.0, 1.0).unwrap().to_hex(), "#000000");
+        // whiteness + blackness above 1.0 is normalized to a gray
+        assert_eq!(Color::from_hwb(90, 0.6, 0.6).unwrap(), Color::from_hwb(0, 0.5, 0.5).unwrap());
+        assert_eq!(Color::from_hwb(90, 1.0, 1.0).unwrap().to_hex(), "#7F7F7F");
+        assert_eq!(Color::from_hwb(0, 1.2, 0.0).unwrap_err(), ColorError::Value);
+
+        assert_eq!(Color::from("hwb(120 0% 0%)").unwrap().to_hex(), "#00FF00");
+        assert_eq!(Color::from("HWB( 0.5turn 0% 0% )").unwrap().to_hex(), "#00FFFF");
+        assert_eq!(Color::from("hwb(210 20% 30%)").unwrap().to_hwb(), "hwb(210 20% 30%)");
+        assert_eq!(Color::from("hwb(0 120% 0%)").unwrap_err(), ColorError::Value);
+        assert!(Color::from("hwb(0,0%,0%)").is_err());
+
+        assert_eq!(Color::from("#00F").unwrap().to_hwb(), "hwb(240 0% 0%)");
+        assert_eq!(Color::from("#808080").unwrap().to_hwb(), "hwb(0 50% 50%)");
+    }
+
+    #[test]
+    fn hsl_alpha_blending() {
+        let color = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+        // to_hsl blends against white
+        assert_eq!(color.to_hsl(), "hsl(0,100%,75%)");
+        // to_hsla keeps the unblended hsl and the alpha
+        assert_eq!(color.to_hsla(), "hsla(0,100%,50%,0.5)");
+        assert_eq!(Color::from(&color.to_hsla()).unwrap(), color);
+        assert_eq!(Color::from(&color.to_hsl()).unwrap().to_hex(), color.to_hex());
+    }
+
+    #[test]
+    fn lch() {
+        let (l, c, h) = Color::from("#FF0000").unwrap().to_lch();
+        let (_, a, b) = Color::from("#FF0000").unwrap().to_lab();
+        assert!((c - (a * a + b * b).sqrt()).abs() < 0.001);
+        assert!((h - b.atan2(a).to_degrees()).abs() < 0.001);
+        assert!((l - 53.24).abs() < 0.05);
+
+        // gray has no chroma
+        let (_, c, _) = Color::from("#808080").unwrap().to_lch();
+        assert!(c < 0.01);
+
+        for hex in ["#104C88", "#FF00AA", "#123456", "#FEDCBA", "#00FF00"] {
+            let color = Color::from(hex).unwrap();
+            let (l, c, h) = color.to_lch();
+            assert!((0.0..360.0).contains(&h));
+            assert_eq!(Color::from_lch(l, c, h).unwrap(), color);
+        }
+
+        // hue wraps around
+        assert_eq!(Color::from_lch(53.24, 104.55, 400.0).unwrap(), Color::from_lch(53.24, 104.55, 40.0).unwrap());
+        assert_eq!(Color::from_lch(53.24, 104.55, -320.0).unwrap(), Color::from_lch(53.24, 104.55, 40.0).unwrap());
+        assert_eq!(Color::from_lch(50.0, -1.0, 0.0).unwrap_err(), ColorError::Value);
+        assert_eq!(Color::from_lch(101.0, 0.0, 0.0).unwrap_err(), ColorError::Value);
+    }
+
+    #[test]
+    fn translucent_outputs() {
+        let color = Color::from_rgba(0, 0, 255, 0.5).unwrap();
+        assert_eq!(color.to_hsva(), "hsva(240,100%,100%,0.5)");
+        assert_eq!(Color::from(&color.to_hsva()).unwrap(), color);
+        assert_eq!(color.to_hsv(), "hsv(240,50%,100%)");
+        // cmyk has no alpha, the color is blended against white
+        assert_eq!(color.to_cmyk(), "cmyk(50,50,0,0)");
+        assert_eq!(color.to_cmyk_percent(), "cmyk(50%,50%,0%,0%)");
+    }
+
+    #[test]
+    fn parse_with_format() {
+        let cases = [
+            ("#FF00AA", ColorFormat::Hex),
+            ("#ff00aa80", ColorFormat::HexAlpha),
+            ("#F0A", ColorFormat::ShortHex),
+            ("#F0A8", ColorFormat::ShortHexAlpha),
+            ("rgb(255, 0, 170)", ColorFormat::Rgb),
+            ("rgba(255, 0, 170, 0.5)", ColorFormat::Rgba),
+            ("hsl(320, 100%, 50%)", ColorFormat::Hsl),
+            ("hsla(320, 100%, 50%, 0.5)", ColorFormat::Hsla),
+            ("rgb(255 0 170)", ColorFormat::Rgb),
+            ("rgb(255 0 170 / 50%)", ColorFormat::Rgba),
+            ("rgba(255 0 170 / 0.5)", ColorFormat::Rgba),
+            ("hsl(320 100% 50%)", ColorFormat::Hsl),
+            ("hsl(320 100% 50% / 0.25)", ColorFormat::Hsla),
+            ("hsla(320deg 100% 50% / 25%)", ColorFormat::Hsla),
+            ("hsv(320, 100%, 100%)", ColorFormat::Hsv),
+            ("hsva(320, 100%, 100%, 0.5)", ColorFormat::Hsva),
+            ("hwb(320 0% 0%)", ColorFormat::Hwb),
+            ("cmyk(0, 100, 33, 0)", ColorFormat::Cmyk),
+            ("HotPink", ColorFormat::Named),
+        ];
+        for (input, format) in cases {
+            let (color, detected) = Color::parse_with_format(input).unwrap();
+            assert_eq!(detected, format, "{input}");
+            assert_eq!(color, Color::from(input).unwrap());
+        }
+        assert_eq!(Color::parse_with_format("#12").unwrap_err(), ColorError::format("#12"));
+        assert_eq!(Color::parse_with_format("rgb(256,0,0)").unwrap_err(), ColorError::Value);
+    }
+
+    #[test]
+    fn hue_red_branch() {
+        let magenta = Color::from("#FF00FF").unwrap();
+        assert_eq!(magenta.to_hsl(), "hsl(300,100%,50%)");
+        assert_eq!(magenta.to_hsv(), "hsv(300,100%,100%)");
+        // red is the largest channel and blue > green
+        let color = Color::from("#FF0080").unwrap();
+        assert_eq!(color.to_hsl(), "hsl(330,100%,50%)");
+        assert_eq!(color.to_hsv(), "hsv(330,100%,100%)");
+        let color = Color::from("#C83264").unwrap();
+        assert_eq!(color.to_hsl(), "hsl(340,60%,49%)");
+        assert_eq!(color.to_hsv(), "hsv(340,75%,78%)");
+        // blue > green with green as the largest channel
+        assert_eq!(Color::from("#00FF80").unwrap().to_hsl(), "hsl(150,100%,50%)");
+    }
+
+    #[test]
+    fn default_is_opaque_black() {
+        assert_eq!(Color::default().to_rgba(), "rgba(0,0,0,1)");
+        assert_eq!(Color::default(), Color::from("#000").unwrap());
+    }
+
+    #[test]
+    fn lab_str() {
+        assert_eq!(Color::from("lab(53.24% 80.09 67.2)").unwrap().to_hex(), "#FF0000");
+        assert_eq!(Color::from("lab(100 0 0)").unwrap().to_hex(), "#FFFFFF");
+        assert_eq!(Color::from("LAB( 0% 0% 0% )").unwrap().to_hex(), "#000000");
+        assert_eq!(Color::from("lab(53.24% 64.072% 53.76%)").unwrap().to_hex(), "#FF0000");
+
+        let (l, a, b) = Color::from("lab(50% 40 59.5)").unwrap().to_lab();
+        assert!((l - 50.0).abs() < 0.5 && (a - 40.0).abs() < 0.5 && (b - 59.5).abs() < 0.5);
+        let (_, format) = Color::parse_with_format("lab(50% -40 -20)").unwrap();
+        assert_eq!(format, ColorFormat::Lab);
+
+        assert_eq!(Color::from("lab(120% 0 0)").unwrap_err(), ColorError::Value);
+        assert!(Color::from("lab(50%,40,59.5)").is_err());
+    }
+
+    #[test]
+    fn lch_str() {
+        assert_eq!(Color::from("lch(53.24% 104.55 40)").unwrap().to_hex(), "#FF0000");
+        assert_eq!(Color::from("lch(53.24 104.55 0.1111turn)").unwrap().to_hex(), "#FF0000");
+
+        let (l, c, h) = Color::from("lch(52% 60 130)").unwrap().to_lch();
+        assert!((l - 52.0).abs() < 0.5 && (c - 60.0).abs() < 0.5 && (h - 130.0).abs() < 0.5);
+        let (_, format) = Color::parse_with_format("LCH(52% 60 130deg)").unwrap();
+        assert_eq!(format, ColorFormat::Lch);
+
+        // chroma 0 is a gray, whatever the hue
+        let gray = Color::from("lch(50% 0 270)").unwrap();
+        assert!(gray.red() == gray.green() && gray.green() == gray.blue());
+        assert_eq!(gray, Color::from("lch(50% 0% 0)").unwrap());
+
+        assert_eq!(Color::from("lch(120% 0 0)").unwrap_err(), ColorError::Value);
+        assert!(Color::from("lch(50% -10 0)").is_err());
+    }
+
+    #[test]
+    fn negate() {
+        let mut color = Color::from("#FF0000").unwrap();
+        color.negate();
+        assert_eq!(color.to_hex(), "#00FFFF");
+        assert_eq!(color.alpha(), 1.0);
+
+        let mut color = Color::from_rgba(10, 20, 30, 0.4).unwrap();
+        color.negate().negate_alpha();
+        assert_eq!(color.to_rgba_tuple(), (245, 235, 225, 0.6));
+    }
+
+    #[test]
+    fn oklab() {
+        let close = |(l1, a1, b1): (f32, f32, f32), (l2, a2, b2): (f32, f32, f32)| {
+            (l1 - l2).abs() < 0.001 && (a1 - a2).abs() < 0.001 && (b1 - b2).abs() < 0.001
+        };
+        assert!(close(Color::from("#FFFFFF").unwrap().to_oklab(), (1.0, 0.0, 0.0)));
+        assert!(close(Color::from("#000000").unwrap().to_oklab(), (0.0, 0.0, 0.0)));
+        assert!(close(Color::from("#FF0000").unwrap().to_oklab(), (0.62796, 0.22486, 0.12585)));
+        assert!(close(Color::from("#0000FF").unwrap().to_oklab(), (0.45201, -0.03246, -0.31153)));
+
+        let (_, c, _) = Color::from("#808080").unwrap().to_oklch();
+        assert!(c < 0.001);
+
+        for hex in ["#FFFFFF", "#000000", "#104C88", "#FF00AA", "#FEDCBA", "#00FF00"] {
+            let color = Color::from(hex).unwrap();
+            let (l, a, b) = color.to_oklab();
+            assert_eq!(Color::from_oklab(l, a, b).unwrap(), color);
+            let (l, c, h) = color.to_oklch();
+            assert_eq!(Color::from_oklch(l, c, h).unwrap(), color);
+        }
+        assert_eq!(Color::from_oklab(1.2, 0.0, 0.0).unwrap_err(), ColorError::Value);
+        assert_eq!(Color::from_oklch(0.5, -0.1, 0.0).unwrap_err(), ColorError::Value);
+
+        let (l, c, h) = Color::from("oklch(70% 0.1 180)").unwrap().to_oklch();
+        assert!((l - 0.7).abs() < 0.01 && (c - 0.1).abs() < 0.01 && (h - 180.0).abs() < 1.0);
+        // out of the sRGB gamut, clamped
+        assert_eq!(Color::from("oklch(70% 0.15 180)").unwrap().to_hex(), "#00BCA2");
+        assert_eq!(Color::from("OKLCH(1 0 0)").unwrap().to_hex(), "#FFFFFF");
+        assert_eq!(Color::from("oklch(62.8% 64.4% 29.23deg)").unwrap().to_hex(), "#FF0000");
+        let (_, format) = Color::parse_with_format("oklch(70% 0.15 180)").unwrap();
+        assert_eq!(format, ColorFormat::Oklch);
+        assert_eq!(Color::from("oklch(120% 0 0)").unwrap_err(), ColorError::Value);
+    }
+
+    #[test]
+    fn error_into_box_dyn_error() {
+        fn parse(s: &str) -> Result<Color, Box<dyn std::error::Error>> {
+            Ok(Color::from(s)?)
+        }
+        assert!(parse("#FFF").is_ok());
+        assert_eq!(parse("#FFFF0").unwrap_err().to_string(), "invalid color format `#FFFF0`");
+        assert_eq!(parse("hsl(0,200%,50%)").unwrap_err().to_string(), "color value out of range");
+    }
+
+    #[test]
+    fn mix_oklab() {
+        let blue = Color::from("#0000FF").unwrap();
+        let yellow = Color::from("#FFFF00").unwrap();
+        assert_eq!(blue.mix_oklab(&yellow, 0.0), blue);
+        assert_eq!(blue.mix_oklab(&yellow, 1.0), yellow);
+
+        // the rgb midpoint is a flat gray, the oklab midpoint keeps its chroma
+        let (_, rgb_chroma, _) = blue.mix(&yellow, 0.5).to_oklch();
+        let (_, oklab_chroma, _) = blue.mix_oklab(&yellow, 0.5).to_oklch();
+        assert!(rgb_chroma < 0.01);
+        assert!(oklab_chroma > 0.05);
+
+        let mixed = Color::from_rgba(255, 0, 0, 0.2).unwrap().mix_oklab(&Color::from_rgba(255, 0, 0, 0.6).unwrap(), 0.5);
+        assert_eq!(mixed.to_rgb_tuple(), (255, 0, 0));
+        assert!((mixed.alpha() - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn error_message_contains_input() {
+        let inputs = ["#12345", "rgb(1,2,x)", "hsl(red)", "hsva(1,2,3)", "cmyk(1%,2%)", "lab(1,2,3)", "oklch(a b c)", "blurple"];
+        for input in inputs {
+            assert!(Color::from(input).unwrap_err().to_string().contains(input), "{input}");
+        }
+        assert!(Color::from_hex("#1234").unwrap_err().to_string().contains("#1234"));
+        assert!(Color::from_hex_alpha("#1234567").unwrap_err().to_string().contains("#1234567"));
+        assert!(Color::from_short_hex_alpha("#12").unwrap_err().to_string().contains("#12"));
+        assert!(Color::from_rgba_str("rgba(1,2,3)").unwrap_err().to_string().contains("rgba(1,2,3)"));
+        assert!(Color::from_hsla_str("hsla(1,2%,3%)").unwrap_err().to_string().contains("hsla(1,2%,3%)"));
+        assert!(Color::from_hwb_str("hwb(1,2%,3%)").unwrap_err().to_string().contains("hwb(1,2%,3%)"));
+        assert!(Color::from_lch_str("lch()").unwrap_err().to_string().contains("lch()"));
+        assert!(Color::from_name("nope").unwrap_err().to_string().contains("nope"));
+    }
+
+    #[test]
+    fn gradient() {
+        let red = Color::from("#FF0000").unwrap();
+        let blue = Color::from_rgba(0, 0, 255, 0.5).unwrap();
+        let ramp = red.gradient(&blue, 3);
+        assert_eq!(ramp.len(), 3);
+        assert_eq!(ramp[0], red);
+        assert_eq!(ramp[2], blue);
+        assert_eq!(ramp[1], red.mix(&blue, 0.5));
+        assert_eq!(ramp[1].to_rgba_tuple(), (128, 0, 128, 0.75));
+
+        assert_eq!(red.gradient(&blue, 0), vec![red, blue]);
+        assert_eq!(red.gradient(&blue, 1), vec![red, blue]);
+        assert_eq!(red.gradient(&blue, 2), vec![red, blue]);
+    }
+
+    #[test]
+    fn from_rgba_clamped() {
+        assert_eq!(Color::from_rgba_clamped(0, 0, 0, 2.0).alpha(), 1.0);
+        assert_eq!(Color::from_rgba_clamped(0, 0, 0, -0.5).alpha(), 0.0);
+        let nan = Color::from_rgba_clamped(0, 0, 0, f32::NAN);
+        assert_eq!(nan.alpha(), 1.0);
+        assert_eq!(nan, nan);
+        assert_eq!(nan.to_rgba(), "rgba(0,0,0,1)");
+        let from_tuple: Color = (0, 0, 0, f32::NAN).into();
+        assert_eq!(from_tuple.alpha(), 1.0);
+        assert_eq!(Color::builder().alpha(f32::NAN).build().alpha(), 1.0);
+        assert_eq!(Color::from_rgba_clamped(1, 2, 3, 0.4), Color::from_rgba(1, 2, 3, 0.4).unwrap());
+        assert_eq!(Color::from_rgba(0, 0, 0, 2.0).unwrap_err(), ColorError::Value);
+
+        // the manipulation methods clamp their ratios
+        let mut color = Color::from_rgba(0, 0, 0, 0.8).unwrap();
+        color.opaquer(5.0);
+        assert_eq!(color.alpha(), 1.0);
+        color.fade(-1.0);
+        assert_eq!(color.alpha(), 1.0);
+        color.fade(2.0);
+        assert_eq!(color.alpha(), 0.0);
+    }
+
+    #[test]
+    fn harmonies() {
+        let color = Color::from("hsla(30, 80%, 40%, 0.5)").unwrap();
+        let hue = |c: &Color| c.to_hsla().trim_start_matches("hsla(").split(',').next().unwrap().parse::<u32>().unwrap();
+        let hues: Vec<u32> = color.tetradic().iter().map(hue).collect();
+        assert_eq!(hues, [30, 120, 210, 300]);
+        assert!(color.tetradic().iter().all(|c| c.alpha() == 0.5));
+        let hues: Vec<u32> = color.triadic().iter().map(hue).collect();
+        assert_eq!(hues, [30, 150, 270]);
+        let hues: Vec<u32> = color.analogous(3, 30.0).iter().map(hue).collect();
+        assert_eq!(hues, [0, 30, 60]);
+        assert!(Color::from("#FF0000").unwrap().tetradic().iter().all(|c| c.to_hsl().ends_with(",100%,50%)")));
+    }
+
+    #[test]
+    fn tint_and_shade() {
+        let color = Color::from_rgba(16, 76, 136, 0.5).unwrap();
+        assert_eq!(color.clone().tint(1.0).to_rgba_tuple(), (255, 255, 255, 0.5));
+        assert_eq!(color.clone().shade(1.0).to_rgba_tuple(), (0, 0, 0, 0.5));
+        assert_eq!(color.clone().tint(0.0).to_rgba_tuple(), color.to_rgba_tuple());
+        // intermediate ratios interpolate linearly
+        assert_eq!(color.clone().tint(0.25).to_rgb_tuple(), (76, 121, 166));
+        assert_eq!(color.clone().shade(0.25).to_rgb_tuple(), (12, 57, 102));
+        assert_eq!(color.clone().shade(2.0).to_rgb_tuple(), (0, 0, 0));
+    }
+
+    #[test]
+    fn tint_and_shade_chain() {
+        let mut color = Color::from("#104C88").unwrap();
+        assert_eq!(color.tint(1.0).to_hex(), "#FFFFFF");
+        assert_eq!(color.shade(1.0).to_hex(), "#000000");
+        // tint and shade differ from the HSL based lighten and darken
+        let mut tinted = Color::from("#104C88").unwrap();
+        let mut lightened = tinted;
+        assert_ne!(tinted.tint(0.2).to_hex(), lightened.lighten(0.2).to_hex());
+    }
+
+    #[test]
+    fn gradient_five_steps() {
+        let black = Color::from("#000").unwrap();
+        let white = Color::from("#fff").unwrap();
+        let ramp: Vec<String> = black.gradient(&white, 5).iter().map(|c| c.to_hex()).collect();
+        assert_eq!(ramp, ["#000000", "#404040", "#808080", "#BFBFBF", "#FFFFFF"]);
+    }
+
+    #[test]
+    fn sort_by_luminance() {
+        let white = Color::from("#fff").unwrap();
+        let gray = Color::from("#808080").unwrap();
+        let black = Color::from("#000").unwrap();
+        let yellow = Color::from("#ff0").unwrap();
+        let blue = Color::from("#00f").unwrap();
+        let mut colors = vec![white, blue, gray, yellow, black];
+        Color::sort_by_luminance(&mut colors);
+        assert_eq!(colors, [black, blue, gray, yellow, white]);
+        assert_eq!(gray.luminance_cmp(&gray), std::cmp::Ordering::Equal);
+        assert_eq!(white.luminance_cmp(&gray), std::cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn gradient_lab() {
+        let blue = Color::from("#0000FF").unwrap();
+        let yellow = Color::from_rgba(255, 255, 0, 0.5).unwrap();
+        let lab = blue.gradient_lab(&yellow, 5);
+        let rgb = blue.gradient(&yellow, 5);
+        assert_eq!(lab.len(), 5);
+        assert_eq!(lab[0], blue);
+        assert_eq!(lab[4], yellow);
+        assert_ne!(lab[2].to_rgb_tuple(), rgb[2].to_rgb_tuple());
+        assert_eq!(lab[2].alpha(), 0.75);
+        // the lab midpoint is lighter than the muddy rgb one
+        let (l_lab, _, _) = lab[2].to_lab();
+        let (l_rgb, _, _) = rgb[2].to_lab();
+        assert!(l_lab > l_rgb);
+        assert_eq!(blue.gradient_lab(&yellow, 1), vec![blue, yellow]);
+    }
+
+    #[test]
+    fn hash_map_key() {
+        use std::collections::HashMap;
+
+        let mut counts: HashMap<Color, usize> = HashMap::new();
+        for input in ["#F00", "hsl(0,100%,50%)", "hsla(0,100%,50%,0.5)", "#FF000080", "rgba(255,0,0,0.5)"] {
+            *counts.entry(Color::from(input).unwrap()).or_default() += 1;
+        }
+        assert_eq!(counts[&Color::from("red").unwrap()], 2);
+        assert_eq!(counts[&Color::from_rgba(255, 0, 0, 0.5).unwrap()], 2);
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    fn nearest_named_gray() {
+        assert_eq!(Color::from("#7F7F7F").unwrap().nearest_named(), "gray");
+        assert_eq!(Color::from("#838383").unwrap().nearest_named(), "gray");
+        assert_eq!(Color::from("#A8A8A8").unwrap().nearest_named(), "darkgray");
+        assert_eq!(Color::from("#D4D4D4").unwrap().nearest_named(), "lightgray");
+        assert_eq!(Color::from("#6A6A6A").unwrap().nearest_named(), "dimgray");
+    }
+
+    #[test]
+    fn with_alpha() {
+        let base = Color::from("#104C88").unwrap();
+        let translucent = base.with_alpha(0.25);
+        assert_eq!(translucent.to_rgba_tuple(), (16, 76, 136, 0.25));
+        assert_eq!(base.alpha(), 1.0);
+        assert_eq!(base.with_alpha(2.0).alpha(), 1.0);
+        assert_eq!(base.with_alpha(-1.0).alpha(), 0.0);
+    }
+
+    #[test]
+    fn rgba_percent() {
+        assert_eq!(Color::from("rgba(50%,0%,100%,0.5)").unwrap().to_rgba_tuple(), (128, 0, 255, 0.5));
+        assert_eq!(Color::from("rgba(100%, 100%, 100%, 1)").unwrap().to_hex(), "#FFFFFF");
+        assert_eq!(Color::from("rgb(50%,0%,100%)").unwrap().to_rgb_tuple(), (128, 0, 255));
+        assert_eq!(Color::from("rgb(20%,40%,60%)").unwrap().to_rgb_tuple(), (51, 102, 153));
+        assert!(matches!(Color::from("rgba(50%,0,100%,0.5)"), Err(ColorError::Format { .. })));
+        assert_eq!(Color::from("rgba(150%,0%,0%,0.5)").unwrap_err(), ColorError::Value);
+        assert_eq!(Color::from("rgba(50%,0%,0%,1.5)").unwrap_err(), ColorError::Value);
+    }
+
+    #[test]
+    fn set_alpha_clamps() {
+        let mut color = Color::from("#104C88").unwrap();
+        color.set_alpha(2.0);
+        assert_eq!(color.alpha(), 1.0);
+        assert_eq!(color.to_rgba(), "rgba(16,76,136,1)");
+        color.set_alpha(-0.5);
+        assert_eq!(color.alpha(), 0.0);
+        color.set_alpha(0.3);
+        assert_eq!(color.alpha(), 0.3);
+        color.set_alpha(f32::NAN);
+        assert_eq!(color.alpha(), 1.0);
+        assert_eq!(color, color);
+        assert_eq!(color.with_alpha(f32::NAN).alpha(), 1.0);
+    }
+
+    #[test]
+    fn rgba_alpha_precision() {
+        assert_eq!(Color::from_rgba(255, 0, 170, 1.0).unwrap().to_rgba(), "rgba(255,0,170,1)");
+        assert_eq!(Color::from_rgba(255, 0, 170, 0.5).unwrap().to_rgba(), "rgba(255,0,170,0.5)");
+        assert_eq!(Color::from_rgba(255, 0, 170, 0.333).unwrap().to_rgba(), "rgba(255,0,170,0.333)");
+        assert_eq!(Color::from_rgba(255, 0, 170, 1.0 / 3.0).unwrap().to_rgba(), "rgba(255,0,170,0.333)");
+        assert_eq!(Color::from_rgba(255, 0, 170, 0.0).unwrap().to_rgba(), "rgba(255,0,170,0)");
+        assert_eq!(Color::from("#FF00AA80").unwrap().to_rgba(), "rgba(255,0,170,0.502)");
+    }
+
+    #[test]
+    fn space_separated_syntax() {
+        assert_eq!(Color::from("rgb(255 0 0 / 50%)").unwrap().to_rgba_tuple(), (255, 0, 0, 0.5));
+        assert_eq!(Color::from("rgb(255 0 0 / 0.5)").unwrap().to_rgba_tuple(), (255, 0, 0, 0.5));
+        assert_eq!(Color::from("rgb(255 0 0)").unwrap(), Color::from("red").unwrap());
+        assert_eq!(Color::from("rgba(100% 0% 0% / 25%)").unwrap().to_rgba_tuple(), (255, 0, 0, 0.25));
+        assert_eq!(Color::from("  RGB( 255  0 0/50% ) ").unwrap().to_rgba_tuple(), (255, 0, 0, 0.5));
+
+        let color = Color::from("hsl(120 50% 50% / 0.25)").unwrap();
+        assert_eq!(color, Color::from_hsla(120, 0.5, 0.5, 0.25).unwrap());
+        assert_eq!(Color::from("hsl(120 50% 50%)").unwrap(), Color::from("hsl(120,50%,50%)").unwrap());
+        assert_eq!(Color::from("hsla(120deg 100% 50% / 50%)").unwrap().to_rgba_tuple(), (0, 255, 0, 0.5));
+        assert_eq!(Color::from("hsl(120 50.5% 40%)").unwrap(), Color::from_hsl(120, 0.505, 0.4).unwrap());
+        assert_eq!(Color::from("hsl(120 50% 40.5% / 0.5)").unwrap(), Color::from_hsla(120, 0.5, 0.405, 0.5).unwrap());
+        assert_eq!(Color::from("hsl(120 .5% 40%)").unwrap(), Color::from_hsl(120, 0.005, 0.4).unwrap());
+        assert_eq!(Color::from("hsl(120 100.5% 40%)").unwrap_err(), ColorError::Value);
+
+        assert_eq!(Color::from("rgb(255 0 0 / 150%)").unwrap_err(), ColorError::Value);
+        assert_eq!(Color::from("rgb(256 0 0)").unwrap_err(), ColorError::Value);
+        assert!(matches!(Color::from("rgb(255 0,0)"), Err(ColorError::Format { .. })));
+        assert!(matches!(Color::from("hsl(120 50% 50% / )"), Err(ColorError::Format { .. })));
+    }
+
+    #[test]
+    fn builder() {
+        let mut builder = Color::builder();
+        assert_eq!(builder.build(), Color::from_rgba(0, 0, 0, 1.0).unwrap());
+        builder = builder.red(16);
+        builder = builder.green(76);
+        assert_eq!(builder.build(), Color::from_rgb(16, 76, 0).unwrap());
+        builder = builder.blue(136).alpha(0.5);
+        assert_eq!(builder.build(), Color::from_rgba(16, 76, 136, 0.5).unwrap());
+        assert_eq!(builder.red(255).build().to_rgba_tuple(), (255, 76, 136, 0.5));
+        assert_eq!(Color::builder().alpha(3.0).build().alpha(), 1.0);
+    }
+
+    #[test]
+    fn over_alpha() {
+        let red = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+        let black = Color::from("#000").unwrap();
+        let white = Color::from("#fff").unwrap();
+        assert_eq!(red.over(&black).to_rgba_tuple(), (127, 0, 0, 1.0));
+        assert_eq!(red.over(&white).to_rgba_tuple(), (255, 127, 127, 1.0));
+
+        // a translucent background gives a translucent result
+        let blue = Color::from_rgba(0, 0, 255, 0.5).unwrap();
+        assert_eq!(red.over(&blue).to_rgba_tuple(), (170, 0, 85, 0.75));
+        // over a transparent background the color is unchanged
+        assert_eq!(red.over(&Color::from_rgba(0, 255, 0, 0.0).unwrap()), red);
+        assert_eq!(Color::from_rgba(9, 9, 9, 0.0).unwrap().over(&Color::from_rgba(1, 1, 1, 0.0).unwrap()), Color(0, 0, 0, 0.0));
+    }
+
+    #[test]
+    fn from_arrays() {
+        let color: Color = [255, 0, 170, 128].into();
+        assert_eq!(color.to_rgb_tuple(), (255, 0, 170));
+        assert!((color.alpha() - 0.5).abs() < 0.01);
+        let opaque: Color = [255, 0, 170].into();
+        assert_eq!(opaque, Color(255, 0, 170, 1.0));
+        let transparent: Color = [1, 2, 3, 0].into();
+        assert_eq!(transparent.alpha(), 0.0);
+        let packed: Color = 0xFF00AA80_u32.into();
+        assert_eq!(packed, color);
+    }
+
+    #[test]
+    fn to_hex_on() {
+        let black = Color::from("#000").unwrap();
+        let white = Color::from("#fff").unwrap();
+        let faded = Color::from_rgba(0, 0, 0, 0.5).unwrap();
+        assert_eq!(faded.to_hex_on(&black), "#000000");
+        assert_eq!(faded.to_hex_on(&white), faded.to_hex());
+        assert_eq!(faded.to_hex(), "#7F7F7F");
+        let navy = Color::from("#000080").unwrap();
+        assert_eq!(Color::from_rgba(255, 255, 255, 0.5).unwrap().to_hex_on(&navy), "#7F7FBF");
+        assert_eq!(Color::from("#FF00AA").unwrap().to_hex_on(&navy), "#FF00AA");
+    }
+
+    #[test]
+    fn to_arrays() {
+        let color = Color::from("#104C8880").unwrap();
+        assert_eq!(color.to_rgb_array(), [16, 76, 136]);
+        assert_eq!(color.to_rgba_array(), [16, 76, 136, 128]);
+        let [r, g, b, a] = color.to_rgba_f32_array();
+        assert!((r - 16.0 / 255.0).abs() < 1e-6 && (g - 76.0 / 255.0).abs() < 1e-6 && (b - 136.0 / 255.0).abs() < 1e-6);
+        assert!((a - 128.0 / 255.0).abs() < 1e-6);
+        assert_eq!(Color::from("#fff").unwrap().to_rgba_f32_array(), [1.0; 4]);
+        let back: Color = color.to_rgba_array().into();
+        assert_eq!(back, color);
+    }
+
+    #[test]
+    fn to_arrays_premultiplied() {
+        let color = Color::from_rgba(200, 100, 50, 0.25).unwrap();
+        assert_eq!(color.to_rgba_array(), [200, 100, 50, 64]);
+        assert_eq!(color.to_rgba_array_premultiplied(), [50, 25, 13, 64]);
+        let opaque = Color::from("#104C88").unwrap();
+        assert_eq!(opaque.to_rgba_array_premultiplied(), opaque.to_rgba_array());
+        assert_eq!(opaque.with_alpha(0.0).to_rgba_array_premultiplied(), [0, 0, 0, 0]);
+
+        assert_eq!(color.to_array(), [200, 100, 50, 64]);
+        assert_eq!(color.to_array_premultiplied(), [50, 25, 13, 64]);
+        assert_eq!(opaque.to_array(), opaque.to_rgba_array());
+    }
+
+    #[test]
+    fn uppercase_prefixes() {
+        assert_eq!(Color::from("RGB(1,2,3)").unwrap(), Color(1, 2, 3, 1.0));
+        assert_eq!(Color::from("Rgba(1,2,3,0.5)").unwrap(), Color(1, 2, 3, 0.5));
+        assert_eq!(Color::from("HSL(120,50%,50%)").unwrap(), Color::from("hsl(120,50%,50%)").unwrap());
+        assert_eq!(Color::from("CMYK(0,100,100,0)").unwrap().to_hex(), "#FF0000");
+        assert_eq!(Color::from("Hsv(0,100%,100%)").unwrap().to_hex(), "#FF0000");
+        assert_eq!(Color::from("HWB(0 0% 0%)").unwrap().to_hex(), "#FF0000");
+    }
+
+    const RED: Color = Color::rgb(255, 0, 0);
+    const TRANSLUCENT_RED: Color = Color::rgba(255, 0, 0, 0.5);
+
+    #[test]
+    fn module_level_consts() {
+        assert_eq!(RED.to_hex(), "#FF0000");
+        assert_eq!(TRANSLUCENT_RED.to_rgba(), "rgba(255,0,0,0.5)");
+        assert_eq!(TRANSLUCENT_RED.over(&RED), RED);
+    }
+
+    #[test]
+    fn opacity_predicates() {
+        let opaque = Color::from("#104C88").unwrap();
+        let transparent = Color::from("transparent").unwrap();
+        let half = Color::from("#104C8880").unwrap();
+        assert!(opaque.is_opaque() && !opaque.is_transparent() && !opaque.is_translucent());
+        assert!(!transparent.is_opaque() && transparent.is_transparent() && !transparent.is_translucent());
+        assert!(!half.is_opaque() && !half.is_transparent() && half.is_translucent());
+    }
+
+    #[test]
+    fn hex_without_hash() {
+        assert_eq!(Color::from_bare_hex("ff00aa").unwrap(), Color::from("#ff00aa").unwrap());
+        assert_eq!(Color::from_bare_hex("f0a").unwrap(), Color::from("#f0a").unwrap());
+        assert_eq!(Color::from_bare_hex("F0A8").unwrap(), Color::from("#F0A8").unwrap());
+        assert_eq!(Color::from_bare_hex(" FF00AA80 ").unwrap(), Color::from("#FF00AA80").unwrap());
+        assert_eq!(Color::from_bare_hex("#FF00AA").unwrap(), Color::from("#FF00AA").unwrap());
+        assert_eq!(Color::from_bare_hex("ff00a").unwrap_err(), ColorError::format("ff00a"));
+        assert_eq!(Color::from_bare_hex("fg00aa").unwrap_err(), ColorError::format("fg00aa"));
+        assert_eq!(Color::from_bare_hex("red").unwrap_err(), ColorError::format("red"));
+        // Color::from needs the '#', so mistyped names stay errors
+        assert_eq!(Color::from("ff00aa").unwrap_err(), ColorError::format("ff00aa"));
+        assert_eq!(Color::from("bad").unwrap_err(), ColorError::format("bad"));
+        assert_eq!(Color::from("cafe").unwrap_err(), ColorError::format("cafe"));
+    }
+
+    #[test]
+    fn approx_eq() {
+        let color = Color::from("#104C88").unwrap();
+        let near = Color::from("#114C87").unwrap();
+        assert!(color.approx_eq(&near, 1));
+        assert!(!color.approx_eq(&near, 0));
+        assert!(color.approx_eq(&color, 0));
+        assert!(!color.approx_eq(&Color::from("#124C88").unwrap(), 1));
+        // alpha within the 0 - 255 step
+        assert!(color.approx_eq(&color.with_alpha(0.999), 0));
+        assert!(!color.approx_eq(&color.with_alpha(0.99), 255));
+    }
+
+    #[test]
+    fn surrounding_whitespace() {
+        assert_eq!(Color::from("  #ff00aa  ").unwrap(), Color(255, 0, 170, 1.0));
+        assert_eq!(Color::from("\trgb(1,2,3)\n").unwrap(), Color(1, 2, 3, 1.0));
+        assert_eq!(Color::from("\r\n hsla(0,100%,50%,0.5) \r\n").unwrap(), Color(255, 0, 0, 0.5));
+        // whitespace inside a value is not removed
+        assert!(Color::from("#ff 00aa").is_err());
+        assert!(Color::from("ff00 aa").is_err());
+    }
+
+    #[test]
+    fn distance() {
+        let color = Color::from("#104C88").unwrap();
+        assert_eq!(color.distance(&color), 0.0);
+        assert_eq!(color.distance_cie76(&color), 0.0);
+        let black = Color::from("#000").unwrap();
+        let white = Color::from("#fff").unwrap();
+        assert!((black.distance(&white) - 255.0 * 3.0_f32.sqrt()).abs() < 0.001);
+        assert_eq!(black.distance(&white), white.distance(&black));
+        assert_eq!(Color::from("#000").unwrap().distance(&Color::from("#030400").unwrap()), 5.0);
+        // the same rgb step is a different perceptual distance depending on the hue
+        let green = Color::from("#00FF00").unwrap().distance_cie76(&Color::from("#00C000").unwrap());
+        let blue = Color::from("#0000FF").unwrap().distance_cie76(&Color::from("#0000C0").unwrap());
+        assert!(green > 0.0 && blue > 0.0 && green != blue);
+    }
+
+    #[test]
+    fn alpha_u8() {
+        let color = Color::from_rgba(255, 0, 0, 0.5).unwrap();
+        assert_eq!(color.alpha_u8(), 128);
+        assert_eq!(color.to_hex_alpha(), "#FF000080");
+        assert_eq!(color.to_alpha_hex(), "#80FF0000");
+        assert_eq!(color.to_rgba_array()[3], color.alpha_u8());
+        assert_eq!(color.to_u32() & 0xFF, color.alpha_u8() as u32);
+        // every byte survives a round trip through the hex output
+        for byte in 0..=255_u8 {
+            let color = Color::from_u32(0xFF000000 | byte as u32);
+            assert_eq!(color.alpha_u8(), byte);
+            assert_eq!(Color::from(&color.to_hex_alpha()).unwrap().alpha_u8(), byte);
+        }
+    }
+
+    #[test]
+    fn is_grayscale() {
+        assert!(Color::from("#808080").unwrap().is_grayscale());
+        assert!(Color::from("#fff").unwrap().is_grayscale());
+        assert!(Color::from("rgba(0,0,0,0)").unwrap().is_grayscale());
+        let near = Color::from("#808081").unwrap();
+        assert!(!near.is_grayscale());
+        assert!(!near.is_grayscale_within(0));
+        assert!(near.is_grayscale_within(1));
+        assert!(!Color::from("#7F8081").unwrap().is_grayscale_within(1));
+        assert!(Color::from("#104C88").unwrap().grayscale().is_grayscale());
+    }
+
+    #[test]
+    fn spin_wraps() {
+        let base = Color::from("hsl(10,100%,50%)").unwrap();
+        let spun = |degrees: i32| {
+            let mut color = base;
+            color.spin(degrees);
+            color.to_hsl()
+        };
+        assert_eq!(spun(30), "hsl(40,100%,50%)");
+        assert_eq!(spun(-30), "hsl(340,100%,50%)");
+        assert_eq!(spun(-10), "hsl(0,100%,50%)");
+        assert_eq!(spun(350), "hsl(0,100%,50%)");
+        assert_eq!(spun(370), "hsl(20,100%,50%)");
+        assert_eq!(spun(-370), "hsl(0,100%,50%)");
+        assert_eq!(spun(720), base.to_hsl());
+        assert_eq!(spun(-390), "hsl(340,100%,50%)");
+    }
+
+    #[test]
+    fn brighten() {
+        let mut black = Color::from("#000").unwrap();
+        assert_eq!(black.brighten(0.2).to_hex(), "#333333");
+        let mut color = Color::from_rgba(255, 100, 0, 0.5).unwrap();
+        color.brighten(0.2);
+        assert_eq!(color.to_rgba_tuple(), (255, 151, 51, 0.5));
+        color.brighten(1.0);
+        assert_eq!(color.to_rgba_tuple(), (255, 255, 255, 0.5));
+        let mut white = Color::from("#fff").unwrap();
+        assert_eq!(white.brighten(0.5).to_hex(), "#FFFFFF");
+        let mut color = Color::from("#104C88").unwrap();
+        assert_eq!(color.brighten(-1.0).to_hex(), "#104C88");
+    }
+}
@@ -0,0 +1,20 @@
+//! `Serialize` and `Deserialize` for `Color`, enabled by the `serde` feature.
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Color;
+
+/// Serializes the color as a `#RRGGBB` or `#RRGGBBAA` string, see the `Display` implementation.
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes the color from any string `Color::from` understands.
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Color::from(&s).map_err(|_| de::Error::custom(format!("invalid color `{}`", s)))
+    }
+}
@@ -1,4 +1,6 @@
-use regex::Match;
+use regex::{Captures, Match};
+
+use crate::{ColorError, ColorResult};
 
 pub fn match_to_num(m: Option<&Match>) -> Option<u8> {
     m.map(|m| m.as_str()).and_then(|s| {
@@ -8,14 +10,252 @@ pub fn match_to_num(m: Option<&Match>) -> Option<u8> {
     })
 }
 
-pub fn match_to_num2(m: Option<&Match>) -> Option<u8> {
-    m.map(|m| m.as_str()).and_then(|s| s.parse::<u8>().ok())
+pub fn to_channel(v: u32) -> ColorResult<u8> {
+    u8::try_from(v).map_err(|_| ColorError::Value)
+}
+
+/// Read the red, green and blue from capture groups 1 - 3, which must be either all numbers in 0 - 255
+/// or all percentages in 0% - 100%, mixing them is a format error.
+pub fn captures_to_rgb(cps: &Captures) -> ColorResult<(u8, u8, u8)> {
+    let input = cps.get(0).map_or("", |m| m.as_str());
+    let values: Vec<&str> = (1..=3).filter_map(|i| cps.get(i)).map(|m| m.as_str()).collect();
+    if values.len() != 3 {
+        return Err(ColorError::format(input));
+    }
+    let percents = values.iter().filter(|v| v.ends_with('%')).count();
+    let mut channels = [0_u8; 3];
+    for (channel, value) in channels.iter_mut().zip(&values) {
+        *channel = match percents {
+            0 => to_channel(value.parse::<u32>().map_err(|_| ColorError::format(input))?)?,
+            3 => {
+                let p = value.trim_end_matches('%').parse::<u32>().map_err(|_| ColorError::format(input))?;
+                if p > 100 {
+                    return Err(ColorError::Value);
+                }
+                (p as f32 * 255.0 / 100.0).round() as u8
+            }
+            _ => return Err(ColorError::format(input)),
+        };
+    }
+    Ok((channels[0], channels[1], channels[2]))
 }
 
+/// Blend a channel with the given alpha against a white background.
 pub fn calc_rgb_with_alpha(v: u8, alpha: f32) -> f32 {
-    v as f32 * alpha + 255.0 * (1.0 - alpha)
+    calc_rgb_with_alpha_on(v, 255, alpha)
+}
+
+/// Blend a channel with the given alpha against the channel `bg` of an opaque background.
+pub fn calc_rgb_with_alpha_on(v: u8, bg: u8, alpha: f32) -> f32 {
+    v as f32 * alpha + bg as f32 * (1.0 - alpha)
 }
 
 pub fn is_valid_num(v: &f32) -> bool {
     (0.0..=1.0).contains(v)
 }
+
+/// Clamp a ratio or weight into 0.0 - 1.0, a NaN ratio becomes 0.0 and leaves the color unchanged.
+pub fn clamp_ratio(ratio: f32) -> f32 {
+    if ratio.is_nan() {
+        0.0
+    } else {
+        ratio.clamp(0.0, 1.0)
+    }
+}
+
+/// Clamp an alpha into 0.0 - 1.0, a NaN alpha becomes 1.0 so a stored alpha is always comparable.
+pub const fn clamp_alpha(a: f32) -> f32 {
+    if a.is_nan() {
+        1.0
+    } else {
+        a.clamp(0.0, 1.0)
+    }
+}
+
+/// Convert hsl to red, green and blue between in 0.0 - 255.0, unrounded, the hue is in degrees in 0.0 - 360.0.
+pub fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    let c = (1.0 - (l * 2.0 - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r, g, b) = match (h / 60.0) as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    ((r + m) * 255.0, (g + m) * 255.0, (b + m) * 255.0)
+}
+
+/// Convert an sRGB channel to linear light, between in 0.0 - 1.0.
+pub fn linearize(v: u8) -> f32 {
+    let c = v as f32 / 255.0;
+    if c <= 0.03928 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Parse a hue with an optional `deg`, `rad`, `grad` or `turn` unit into whole degrees in 0 - 359.
+pub fn parse_hue(s: &str) -> Option<u32> {
+    parse_angle(s).map(|degrees| degrees.round() as u32 % 360)
+}
+
+/// Parse a hue with an optional `deg`, `rad`, `grad` or `turn` unit into degrees in 0.0 - 360.0.
+pub fn parse_angle(s: &str) -> Option<f32> {
+    let (value, degrees_per_unit) = if let Some(v) = s.strip_suffix("deg") {
+        (v, 1.0)
+    } else if let Some(v) = s.strip_suffix("grad") {
+        (v, 0.9)
+    } else if let Some(v) = s.strip_suffix("rad") {
+        (v, 180.0 / std::f32::consts::PI)
+    } else if let Some(v) = s.strip_suffix("turn") {
+        (v, 360.0)
+    } else {
+        (s, 1.0)
+    };
+    let degrees = value.parse::<f32>().ok()? * degrees_per_unit;
+    Some(degrees.rem_euclid(360.0))
+}
+
+/// Normalize a color string before parsing: lowercase it, trim it, collapse any whitespace to a single space,
+/// and drop the whitespace around `(`, `)`, `,`, `/` and before `%`.
+pub fn normalize(s: &str) -> String {
+    let s = s.trim().to_lowercase();
+    let mut out = String::with_capacity(s.len());
+    let mut pending_space = false;
+    for c in s.chars() {
+        if c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space && !matches!(c, '(' | ')' | ',' | '/' | '%') && !out.ends_with(['(', ',', '/']) {
+            out.push(' ');
+        }
+        pending_space = false;
+        out.push(c);
+    }
+    out
+}
+
+/// Convert a linear light channel, between in 0.0 - 1.0, back to sRGB, clamping out of gamut values.
+pub fn delinearize(c: f32) -> u8 {
+    let v = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (v.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// The D65 white point in CIE XYZ, with Y of white being 1.0.
+pub const D65: (f32, f32, f32) = (0.95047, 1.0, 1.08883);
+
+/// Convert sRGB channels to CIE XYZ (D65), with Y of white being 1.0.
+pub fn rgb_to_xyz(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = (linearize(r), linearize(g), linearize(b));
+    (
+        0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+        0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+        0.0193339 * r + 0.119192 * g + 0.9503041 * b,
+    )
+}
+
+/// Convert CIE XYZ (D65) to sRGB channels, clamping out of gamut values.
+pub fn xyz_to_rgb(x: f32, y: f32, z: f32) -> (u8, u8, u8) {
+    (
+        delinearize(3.2404542 * x - 1.5371385 * y - 0.4985314 * z),
+        delinearize(-0.969266 * x + 1.8760108 * y + 0.041556 * z),
+        delinearize(0.0556434 * x - 0.2040259 * y + 1.0572252 * z),
+    )
+}
+
+/// Convert CIE XYZ (D65) to CIELAB.
+pub fn xyz_to_lab(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    let f = |t: f32| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x / D65.0), f(y / D65.1), f(z / D65.2));
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// Convert CIELAB to CIE XYZ (D65).
+pub fn lab_to_xyz(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let f_inv = |t: f32| {
+        if t.powi(3) > 216.0 / 24389.0 {
+            t.powi(3)
+        } else {
+            (116.0 * t - 16.0) * 27.0 / 24389.0
+        }
+    };
+    (f_inv(fx) * D65.0, f_inv(fy) * D65.1, f_inv(fz) * D65.2)
+}
+
+/// Convert CIELAB to CIE LCh, with the hue in degrees in 0.0 - 360.0.
+pub fn lab_to_lch(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    (l, a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0))
+}
+
+/// Convert CIE LCh, with the hue in degrees, to CIELAB.
+pub fn lch_to_lab(l: f32, c: f32, h: f32) -> (f32, f32, f32) {
+    let h = h.to_radians();
+    (l, c * h.cos(), c * h.sin())
+}
+
+/// Parse a number, or a percentage where 100% is `percent_scale`, as used by the CSS `lab()` and `lch()` notations.
+pub fn parse_number_or_percent(s: &str, percent_scale: f32) -> Option<f32> {
+    match s.strip_suffix('%') {
+        Some(p) => p.parse::<f32>().ok().map(|p| p / 100.0 * percent_scale),
+        None => s.parse::<f32>().ok(),
+    }
+}
+
+/// Convert sRGB channels to OKLab.
+pub fn rgb_to_oklab(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = (linearize(r), linearize(g), linearize(b));
+    let l = (0.41222147 * r + 0.53633254 * g + 0.05144599 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+    let s = (0.08830246 * r + 0.28171884 * g + 0.6299787 * b).cbrt();
+    (
+        0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+        1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+        0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+    )
+}
+
+/// Convert OKLab to sRGB channels, clamping out of gamut values.
+pub fn oklab_to_rgb(l: f32, a: f32, b: f32) -> (u8, u8, u8) {
+    let l_ = (l + 0.39633778 * a + 0.21580376 * b).powi(3);
+    let m_ = (l - 0.105561346 * a - 0.06385417 * b).powi(3);
+    let s_ = (l - 0.08948418 * a - 1.2914855 * b).powi(3);
+    (
+        delinearize(4.0767417 * l_ - 3.3077116 * m_ + 0.23096994 * s_),
+        delinearize(-1.268438 * l_ + 2.6097574 * m_ - 0.34131938 * s_),
+        delinearize(-0.0041960864 * l_ - 0.7034186 * m_ + 1.7076147 * s_),
+    )
+}
+
+/// Format an alpha with up to 3 decimals, trimming trailing zeros, ex: `1`, `0.5`, `0.333`.
+pub fn format_alpha(alpha: f32) -> String {
+    let s = format!("{:.3}", alpha);
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Like `format_alpha`, but a whole alpha keeps one decimal, ex: `1.0`, `0.5`, `0.333`, as `hsla()` and `hsva()` print it.
+pub fn format_alpha_decimal(alpha: f32) -> String {
+    let s = format_alpha(alpha);
+    if s.contains('.') {
+        s
+    } else {
+        s + ".0"
+    }
+}